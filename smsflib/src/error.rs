@@ -1,8 +1,179 @@
-#[derive(Debug, PartialEq)]
+/// Reasons why a stack operation failed.
+///
+/// Failed operations leave the stack unchanged. Errors caused by the values of the operands
+/// carry the name of the operation and the operands, formatted with [Debug](std::fmt::Debug)
+/// and ordered by level, starting with X.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StackError {
+    /// The stack has fewer levels than the operation consumes
     NotEnoughOperands {
         num_required: usize,
         num_available: usize,
     },
+    /// The operands are outside the domain of the operation, e.g. `ln(-1)`
+    DomainError {
+        operation: &'static str,
+        operands: Vec<String>,
+    },
+    /// The divisor is zero
+    DivisionByZero {
+        operation: &'static str,
+        operands: Vec<String>,
+    },
+    /// The result does not fit into the element type
+    Overflow {
+        operation: &'static str,
+        operands: Vec<String>,
+    },
+    /// No operation with this name exists
+    UnknownOperation { name: String },
+    /// The operands have a type or kind the operation cannot handle
+    TypeMismatch {
+        operation: &'static str,
+        expected: &'static str,
+        operands: Vec<String>,
+    },
+    /// The operation would grow the stack beyond its maximum depth
+    StackOverflow { max_depth: usize },
+    /// The LastX register is recalled before any operation saved a value
+    EmptyLastX,
+    /// Any other failure
     Other,
 }
+
+impl StackError {
+    /// Create a [DomainError](StackError::DomainError)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let e = smsflib::StackError::domain_error("ln", &[-1.0]);
+    ///
+    /// assert_eq!(e.to_string(), "domain error in 'ln' for -1.0");
+    /// ```
+    pub fn domain_error<T: std::fmt::Debug>(operation: &'static str, operands: &[T]) -> Self {
+        StackError::DomainError {
+            operation,
+            operands: format_operands(operands),
+        }
+    }
+
+    /// Create a [DivisionByZero](StackError::DivisionByZero) error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let e = smsflib::StackError::division_by_zero("divide", &[0, 5]);
+    ///
+    /// assert_eq!(e.to_string(), "division by zero in 'divide' for 0, 5");
+    /// ```
+    pub fn division_by_zero<T: std::fmt::Debug>(operation: &'static str, operands: &[T]) -> Self {
+        StackError::DivisionByZero {
+            operation,
+            operands: format_operands(operands),
+        }
+    }
+
+    /// Create an [Overflow](StackError::Overflow) error
+    pub fn overflow<T: std::fmt::Debug>(operation: &'static str, operands: &[T]) -> Self {
+        StackError::Overflow {
+            operation,
+            operands: format_operands(operands),
+        }
+    }
+
+    /// Create a [TypeMismatch](StackError::TypeMismatch) error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let e = smsflib::StackError::type_mismatch("factorial", "an integer", &[2.5]);
+    ///
+    /// assert_eq!(e.to_string(), "type mismatch in 'factorial': expected an integer, found 2.5");
+    /// ```
+    pub fn type_mismatch<T: std::fmt::Debug>(
+        operation: &'static str,
+        expected: &'static str,
+        operands: &[T],
+    ) -> Self {
+        StackError::TypeMismatch {
+            operation,
+            expected,
+            operands: format_operands(operands),
+        }
+    }
+}
+
+fn format_operands<T: std::fmt::Debug>(operands: &[T]) -> Vec<String> {
+    operands
+        .iter()
+        .map(|operand| format!("{:?}", operand))
+        .collect()
+}
+
+impl std::fmt::Display for StackError {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::StackError;
+    ///
+    /// let e = StackError::NotEnoughOperands { num_required: 2, num_available: 1 };
+    /// assert_eq!(e.to_string(), "not enough operands: 2 required, 1 available");
+    ///
+    /// let e = StackError::UnknownOperation { name: "foo".to_string() };
+    /// assert_eq!(e.to_string(), "unknown operation 'foo'");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StackError::NotEnoughOperands {
+                num_required,
+                num_available,
+            } => write!(
+                f,
+                "not enough operands: {} required, {} available",
+                num_required, num_available
+            ),
+            StackError::DomainError {
+                operation,
+                operands,
+            } => write!(
+                f,
+                "domain error in '{}' for {}",
+                operation,
+                operands.join(", ")
+            ),
+            StackError::DivisionByZero {
+                operation,
+                operands,
+            } => write!(
+                f,
+                "division by zero in '{}' for {}",
+                operation,
+                operands.join(", ")
+            ),
+            StackError::Overflow {
+                operation,
+                operands,
+            } => write!(f, "overflow in '{}' for {}", operation, operands.join(", ")),
+            StackError::UnknownOperation { name } => write!(f, "unknown operation '{}'", name),
+            StackError::TypeMismatch {
+                operation,
+                expected,
+                operands,
+            } => write!(
+                f,
+                "type mismatch in '{}': expected {}, found {}",
+                operation,
+                expected,
+                operands.join(", ")
+            ),
+            StackError::StackOverflow { max_depth } => {
+                write!(f, "stack overflow: the maximum depth is {}", max_depth)
+            }
+            StackError::EmptyLastX => write!(f, "the LastX register is empty"),
+            StackError::Other => write!(f, "stack error"),
+        }
+    }
+}
+
+impl std::error::Error for StackError {}
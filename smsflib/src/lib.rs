@@ -1,5 +1,7 @@
 mod error;
+pub mod number;
 pub mod prelude;
+pub mod registry;
 pub mod stack;
 
 pub use error::StackError;
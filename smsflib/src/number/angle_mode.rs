@@ -0,0 +1,195 @@
+use num_traits::Float;
+
+/// Unit of the angles taken and returned by trigonometric operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AngleMode {
+    /// 360 per full turn
+    Degrees,
+    /// 2π per full turn
+    #[default]
+    Radians,
+    /// 400 per full turn
+    Gradians,
+    /// 1 per full turn
+    Turns,
+}
+
+impl AngleMode {
+    /// Convert an angle in this unit into radians
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// assert_approx_eq!(AngleMode::Degrees.to_radians(180.0), std::f64::consts::PI);
+    /// assert_approx_eq!(AngleMode::Gradians.to_radians(100.0), std::f64::consts::FRAC_PI_2);
+    /// assert_approx_eq!(AngleMode::Turns.to_radians(0.25), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(AngleMode::Radians.to_radians(1.5), 1.5);
+    /// ```
+    pub fn to_radians<T: Float>(self, angle: T) -> T {
+        match self {
+            AngleMode::Degrees => angle.to_radians(),
+            AngleMode::Radians => angle,
+            _ => angle / self.half_turn() * pi(),
+        }
+    }
+
+    /// Convert an angle in radians into this unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// assert_approx_eq!(AngleMode::Degrees.from_radians(std::f64::consts::PI), 180.0);
+    /// assert_approx_eq!(AngleMode::Gradians.from_radians(std::f64::consts::FRAC_PI_2), 100.0);
+    /// assert_approx_eq!(AngleMode::Turns.from_radians(std::f64::consts::FRAC_PI_2), 0.25);
+    /// assert_eq!(AngleMode::Radians.from_radians(1.5), 1.5);
+    /// ```
+    pub fn from_radians<T: Float>(self, angle: T) -> T {
+        match self {
+            AngleMode::Degrees => angle.to_degrees(),
+            AngleMode::Radians => angle,
+            _ => angle / pi() * self.half_turn(),
+        }
+    }
+
+    /// Convert an angle in this unit into the `target` unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// assert_eq!(AngleMode::Degrees.convert(45.0, AngleMode::Gradians), 50.0);
+    /// assert_eq!(AngleMode::Turns.convert(0.75, AngleMode::Degrees), 270.0);
+    /// assert_approx_eq!(AngleMode::Radians.convert(std::f64::consts::PI, AngleMode::Degrees), 180.0);
+    /// ```
+    pub fn convert<T: Float>(self, angle: T, target: AngleMode) -> T {
+        match (self, target) {
+            (AngleMode::Radians, _) => target.from_radians(angle),
+            (_, AngleMode::Radians) => self.to_radians(angle),
+            _ => angle / self.half_turn() * target.half_turn(),
+        }
+    }
+
+    /// Sine of an angle in this unit, exact at multiples of a quarter turn
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    ///
+    /// assert_eq!(AngleMode::Degrees.sin(180.0), 0.0);
+    /// assert_eq!(AngleMode::Degrees.sin(-90.0), -1.0);
+    /// assert_eq!(AngleMode::Gradians.sin(100.0), 1.0);
+    /// assert_eq!(AngleMode::Turns.sin(1e15 + 0.5), 0.0);
+    /// assert!(AngleMode::Degrees.sin(f64::INFINITY).is_nan());
+    /// ```
+    pub fn sin<T: Float>(self, angle: T) -> T {
+        match self.quarter_turns(angle) {
+            Ok(quarter_turns) => [T::zero(), T::one(), T::zero(), -T::one()][quarter_turns],
+            Err(reduced) => self.to_radians(reduced).sin(),
+        }
+    }
+
+    /// Cosine of an angle in this unit, exact at multiples of a quarter turn
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    ///
+    /// assert_eq!(AngleMode::Degrees.cos(90.0), 0.0);
+    /// assert_eq!(AngleMode::Degrees.cos(540.0), -1.0);
+    /// assert_eq!(AngleMode::Gradians.cos(100.0), 0.0);
+    /// assert_eq!(AngleMode::Turns.cos(-0.25), 0.0);
+    /// ```
+    pub fn cos<T: Float>(self, angle: T) -> T {
+        match self.quarter_turns(angle) {
+            Ok(quarter_turns) => [T::one(), T::zero(), -T::one(), T::zero()][quarter_turns],
+            Err(reduced) => self.to_radians(reduced).cos(),
+        }
+    }
+
+    /// Tangent of an angle in this unit, exact at multiples of a quarter turn.
+    ///
+    /// The tangent of an odd number of quarter turns is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    ///
+    /// assert_eq!(AngleMode::Degrees.tan(180.0), 0.0);
+    /// assert!(AngleMode::Degrees.tan(90.0_f64).is_nan());
+    /// assert!(AngleMode::Gradians.tan(-100.0_f64).is_nan());
+    /// ```
+    pub fn tan<T: Float>(self, angle: T) -> T {
+        match self.quarter_turns(angle) {
+            Ok(quarter_turns) if quarter_turns % 2 == 0 => T::zero(),
+            Ok(_) => T::nan(),
+            Err(reduced) => self.to_radians(reduced).tan(),
+        }
+    }
+
+    /// Reduce an angle modulo a full turn.
+    ///
+    /// Return the number of quarter turns in `0..4` if the angle is a multiple of a quarter turn,
+    /// otherwise the reduced angle. Angles in radians are never reduced, since π is not exact.
+    fn quarter_turns<T: Float>(self, angle: T) -> Result<usize, T> {
+        if self == AngleMode::Radians || !angle.is_finite() {
+            return Err(angle);
+        }
+        let quarter_turn = self.half_turn::<T>() / (T::one() + T::one());
+        let reduced = angle % (quarter_turn * T::from(4).unwrap());
+        let quarter_turns = (reduced / quarter_turn).round();
+        if quarter_turns * quarter_turn == reduced {
+            // -3..=3 quarter turns, shifted into 0..4
+            Ok((quarter_turns.to_i32().unwrap() + 4) as usize % 4)
+        } else {
+            Err(reduced)
+        }
+    }
+
+    /// Size of half a turn in this unit
+    fn half_turn<T: Float>(self) -> T {
+        match self {
+            AngleMode::Degrees => T::from(180).unwrap(),
+            AngleMode::Radians => pi(),
+            AngleMode::Gradians => T::from(200).unwrap(),
+            AngleMode::Turns => T::from(0.5).unwrap(),
+        }
+    }
+}
+
+/// π in the precision of `T`
+fn pi<T: Float>() -> T {
+    T::one().atan() * T::from(4).unwrap()
+}
+
+impl std::fmt::Display for AngleMode {
+    /// Print the annunciator shown by calculators
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::AngleMode;
+    ///
+    /// assert_eq!(AngleMode::Degrees.to_string(), "DEG");
+    /// assert_eq!(AngleMode::Turns.to_string(), "TURN");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let annunciator = match self {
+            AngleMode::Degrees => "DEG",
+            AngleMode::Radians => "RAD",
+            AngleMode::Gradians => "GRAD",
+            AngleMode::Turns => "TURN",
+        };
+        write!(f, "{}", annunciator)
+    }
+}
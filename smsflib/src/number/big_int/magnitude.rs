@@ -0,0 +1,271 @@
+//! Unsigned arithmetic on little-endian `u64` limbs.
+//!
+//! Magnitudes are normalised, i.e. have no most significant zero limbs, so that zero is the
+//! empty slice. Inputs may be unnormalised slices of a normalised magnitude, results are
+//! always normalised.
+
+use std::cmp::Ordering;
+
+/// Operand length in limbs from which multiplication switches to Karatsuba's method
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Remove the most significant zero limbs
+pub(super) fn normalize(limbs: &mut Vec<u64>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+/// Strip the most significant zero limbs of a slice
+fn trimmed(limbs: &[u64]) -> &[u64] {
+    let len = limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |idx| idx + 1);
+    &limbs[..len]
+}
+
+pub(super) fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+    let (a, b) = (trimmed(a), trimmed(b));
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub(super) fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut sum = a.to_vec();
+    add_assign_shifted(&mut sum, b, 0);
+    sum
+}
+
+/// Add `b * 2^(64 * shift)` to `acc`
+fn add_assign_shifted(acc: &mut Vec<u64>, b: &[u64], shift: usize) {
+    let b = trimmed(b);
+    if acc.len() < shift + b.len() {
+        acc.resize(shift + b.len(), 0);
+    }
+    let mut carry = false;
+    for (acc_limb, &b_limb) in acc[shift..].iter_mut().zip(b) {
+        let (limb, overflow_1) = acc_limb.overflowing_add(b_limb);
+        let (limb, overflow_2) = limb.overflowing_add(u64::from(carry));
+        *acc_limb = limb;
+        carry = overflow_1 || overflow_2;
+    }
+    let mut idx = shift + b.len();
+    while carry {
+        if idx == acc.len() {
+            acc.push(1);
+            break;
+        }
+        let (limb, overflow) = acc[idx].overflowing_add(1);
+        acc[idx] = limb;
+        carry = overflow;
+        idx += 1;
+    }
+    normalize(acc);
+}
+
+/// `a - b` for `a >= b`
+pub(super) fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut difference = a.to_vec();
+    sub_assign(&mut difference, b);
+    difference
+}
+
+/// `acc -= b` for `acc >= b`
+fn sub_assign(acc: &mut Vec<u64>, b: &[u64]) {
+    let b = trimmed(b);
+    debug_assert!(
+        cmp(acc, b) != Ordering::Less,
+        "magnitude subtraction underflow"
+    );
+    let mut borrow = false;
+    for (idx, acc_limb) in acc.iter_mut().enumerate() {
+        if idx >= b.len() && !borrow {
+            break;
+        }
+        let (limb, underflow_1) = acc_limb.overflowing_sub(b.get(idx).copied().unwrap_or(0));
+        let (limb, underflow_2) = limb.overflowing_sub(u64::from(borrow));
+        *acc_limb = limb;
+        borrow = underflow_1 || underflow_2;
+    }
+    normalize(acc);
+}
+
+pub(super) fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.is_empty() {
+        Vec::new()
+    } else if short.len() < KARATSUBA_THRESHOLD {
+        mul_schoolbook(long, short)
+    } else if long.len() >= 2 * short.len() {
+        // Multiply the long operand in slices as long as the short one, so that every
+        // product is balanced
+        let mut product = Vec::with_capacity(long.len() + short.len());
+        for (idx, chunk) in long.chunks(short.len()).enumerate() {
+            add_assign_shifted(&mut product, &mul(chunk, short), idx * short.len());
+        }
+        product
+    } else {
+        mul_karatsuba(long, short)
+    }
+}
+
+fn mul_schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &b_limb) in b.iter().enumerate() {
+            let t = u128::from(a_limb) * u128::from(b_limb) + u128::from(product[i + j]) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    normalize(&mut product);
+    product
+}
+
+/// Karatsuba multiplication for `a.len() >= b.len() > a.len() / 2`
+fn mul_karatsuba(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let half = a.len() / 2;
+    let (a_low, a_high) = a.split_at(half);
+    let (b_low, b_high) = b.split_at(half);
+    let low = mul(a_low, b_low);
+    let high = mul(a_high, b_high);
+    // (a_low + a_high)(b_low + b_high) - low - high = a_low b_high + a_high b_low
+    let mut middle = mul(&add(a_low, a_high), &add(b_low, b_high));
+    sub_assign(&mut middle, &low);
+    sub_assign(&mut middle, &high);
+
+    let mut product = low;
+    add_assign_shifted(&mut product, &middle, half);
+    add_assign_shifted(&mut product, &high, 2 * half);
+    product
+}
+
+/// Quotient and remainder of `a / d` for a non-zero limb `d`
+pub(super) fn div_rem_limb(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut quotient = vec![0; a.len()];
+    let mut remainder = 0u128;
+    for (q, &limb) in quotient.iter_mut().zip(a).rev() {
+        let t = (remainder << 64) | u128::from(limb);
+        *q = (t / u128::from(d)) as u64;
+        remainder = t % u128::from(d);
+    }
+    normalize(&mut quotient);
+    (quotient, remainder as u64)
+}
+
+/// Quotient and remainder of `a / b` for a non-zero `b`
+/// (D. Knuth, The Art of Computer Programming, Vol. 2, 4.3.1, Algorithm D)
+pub(super) fn div_rem(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let (a, b) = (trimmed(a), trimmed(b));
+    assert!(!b.is_empty(), "division of a magnitude by zero");
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, remainder) = div_rem_limb(a, b[0]);
+        let mut remainder = vec![remainder];
+        normalize(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    // Normalise so that the most significant limb of the divisor has its top bit set,
+    // which keeps the estimated quotient limbs at most two too large
+    let shift = b[b.len() - 1].leading_zeros();
+    let v = shl_bits(b, shift);
+    let mut u = shl_bits(a, shift);
+    u.resize(a.len() + 1, 0);
+    let n = v.len();
+    let (v_top, v_next) = (u128::from(v[n - 1]), u128::from(v[n - 2]));
+    let limb_base = 1u128 << 64;
+
+    let mut quotient = vec![0; u.len() - n];
+    for j in (0..quotient.len()).rev() {
+        let numerator = (u128::from(u[j + n]) << 64) | u128::from(u[j + n - 1]);
+        let mut q_hat = numerator / v_top;
+        let mut r_hat = numerator % v_top;
+        while q_hat >= limb_base
+            || (r_hat < limb_base && q_hat * v_next > (r_hat << 64) | u128::from(u[j + n - 2]))
+        {
+            q_hat -= 1;
+            r_hat += v_top;
+        }
+
+        // u[j..=j + n] -= q_hat * v
+        let mut carry = 0u128;
+        let mut borrow = false;
+        for i in 0..n {
+            let p = q_hat * u128::from(v[i]) + carry;
+            carry = p >> 64;
+            let (limb, underflow_1) = u[i + j].overflowing_sub(p as u64);
+            let (limb, underflow_2) = limb.overflowing_sub(u64::from(borrow));
+            u[i + j] = limb;
+            borrow = underflow_1 || underflow_2;
+        }
+        let (limb, underflow_1) = u[j + n].overflowing_sub(carry as u64);
+        let (limb, underflow_2) = limb.overflowing_sub(u64::from(borrow));
+        u[j + n] = limb;
+
+        if underflow_1 || underflow_2 {
+            // The estimate was one too large, add the divisor back
+            q_hat -= 1;
+            let mut carry = false;
+            for i in 0..n {
+                let (limb, overflow_1) = u[i + j].overflowing_add(v[i]);
+                let (limb, overflow_2) = limb.overflowing_add(u64::from(carry));
+                u[i + j] = limb;
+                carry = overflow_1 || overflow_2;
+            }
+            u[j + n] = u[j + n].wrapping_add(u64::from(carry));
+        }
+        quotient[j] = q_hat as u64;
+    }
+    normalize(&mut quotient);
+    let remainder = shr_bits(&u[..n], shift);
+    (quotient, remainder)
+}
+
+/// `a * 2^shift` for `shift < 64`
+fn shl_bits(a: &[u64], shift: u32) -> Vec<u64> {
+    let mut shifted = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &limb in a {
+        shifted.push((limb << shift) | carry);
+        carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+    }
+    shifted.push(carry);
+    normalize(&mut shifted);
+    shifted
+}
+
+/// `a / 2^shift` for `shift < 64`
+fn shr_bits(a: &[u64], shift: u32) -> Vec<u64> {
+    let mut shifted = vec![0; a.len()];
+    for idx in 0..a.len() {
+        let high = match a.get(idx + 1) {
+            Some(&next) if shift > 0 => next << (64 - shift),
+            _ => 0,
+        };
+        shifted[idx] = (a[idx] >> shift) | high;
+    }
+    normalize(&mut shifted);
+    shifted
+}
+
+/// `a * factor + summand` in place
+pub(super) fn mul_limb_add_assign(a: &mut Vec<u64>, factor: u64, summand: u64) {
+    let mut carry = u128::from(summand);
+    for limb in a.iter_mut() {
+        let t = u128::from(*limb) * u128::from(factor) + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    if carry > 0 {
+        a.push(carry as u64);
+    }
+    normalize(a);
+}
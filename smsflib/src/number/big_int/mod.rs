@@ -0,0 +1,699 @@
+/// Unsigned limb arithmetic
+mod magnitude;
+/// Digit string conversion
+mod radix;
+
+use super::{ArithmeticPolicy, OverflowArithmetic};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Signed integer of arbitrary size.
+///
+/// Multiplication uses Karatsuba's method for large operands, division Knuth's Algorithm D.
+/// Division and remainder truncate towards zero like the primitive integers.
+///
+/// Stacks of big integers support the
+/// [BasicMathOperations](crate::stack::BasicMathOperations),
+/// [SignedMathOperations](crate::stack::SignedMathOperations) and
+/// [PercentageOperations](crate::stack::PercentageOperations). Results never overflow,
+/// the arithmetic policy has no effect.
+///
+/// # Example
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = DynamicSizedStack::<BigInt>::new();
+/// stack.push(BigInt::from(2).pow(200));
+/// stack.push(BigInt::from(u64::MAX));
+/// stack.multiply();
+///
+/// assert_eq!(
+///     stack.get(0).unwrap().to_string(),
+///     "29642774844752946026827234117965114134895153982062821791578938512242968352522240"
+/// );
+/// ```
+///
+/// Factorials by repeated multiplication:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = ClassicStack::<BigInt>::new_zero();
+/// stack.push(BigInt::from(1));
+/// for n in 2..=30 {
+///     stack.push(BigInt::from(n));
+///     stack.multiply();
+/// }
+///
+/// assert_eq!(stack.x().to_string(), "265252859812191058636308480000000");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    /// Never set for zero
+    negative: bool,
+    /// Little-endian limbs without most significant zero limbs
+    magnitude: Vec<u64>,
+}
+
+/// Reason why a string could not be parsed into a [BigInt]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    /// The string has no digits
+    Empty,
+    /// The string contains a character which is not a digit in the radix
+    InvalidDigit,
+}
+
+impl std::fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit => write!(f, "invalid digit found in string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl BigInt {
+    /// Create a value from its sign and normalise it
+    fn from_parts(negative: bool, mut magnitude: Vec<u64>) -> Self {
+        magnitude::normalize(&mut magnitude);
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    /// Raise to the power of `exponent` by repeated squaring
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// assert_eq!(
+    ///     BigInt::from(2).pow(200).to_string(),
+    ///     "1606938044258990275541962092341162602522202993782792835301376"
+    /// );
+    /// assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+    /// assert_eq!(BigInt::from(0).pow(0), BigInt::from(1));
+    /// ```
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut result = BigInt::one();
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Format with lowercase digits in a radix from 2 to 36
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside of 2 to 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// let value = BigInt::from(-255);
+    ///
+    /// assert_eq!(value.to_str_radix(16), "-ff");
+    /// assert_eq!(value.to_str_radix(2), "-11111111");
+    /// assert_eq!(BigInt::from(u128::MAX).to_str_radix(36), "f5lxx1zz5pnorynqglhzmsp33");
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = radix::to_str_radix(&self.magnitude, radix);
+        if self.negative {
+            format!("-{}", digits)
+        } else {
+            digits
+        }
+    }
+
+    /// Number of bits of the magnitude, zero for zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0).bits(), 0);
+    /// assert_eq!(BigInt::from(-256).bits(), 9);
+    /// ```
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => 64 * self.magnitude.len() as u64 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Truncated quotient and remainder, [None] if `rhs` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// let (quotient, remainder) = BigInt::from(-7).checked_div_rem(&BigInt::from(2)).unwrap();
+    ///
+    /// assert_eq!(quotient, BigInt::from(-3));
+    /// assert_eq!(remainder, BigInt::from(-1));
+    /// assert_eq!(BigInt::from(1).checked_div_rem(&BigInt::from(0)), None);
+    /// ```
+    pub fn checked_div_rem(&self, rhs: &BigInt) -> Option<(BigInt, BigInt)> {
+        if rhs.magnitude.is_empty() {
+            return None;
+        }
+        let (quotient, remainder) = magnitude::div_rem(&self.magnitude, &rhs.magnitude);
+        Some((
+            BigInt::from_parts(self.negative != rhs.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        ))
+    }
+
+    /// Truncated remainder, [None] if `rhs` is zero
+    pub fn checked_rem(&self, rhs: &BigInt) -> Option<BigInt> {
+        self.checked_div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+
+    /// Sum of two values with the given signs
+    fn add_signed(&self, rhs: &BigInt, rhs_negative: bool) -> BigInt {
+        if self.negative == rhs_negative {
+            return BigInt::from_parts(
+                self.negative,
+                magnitude::add(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match magnitude::cmp(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                rhs_negative,
+                magnitude::sub(&rhs.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                magnitude::sub(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+/// Implement the conversions from primitive integers
+macro_rules! big_int_from_primitive {
+    ($($t:ty)*) => {$(
+        impl From<$t> for BigInt {
+            #[allow(unused_comparisons)]
+            fn from(value: $t) -> Self {
+                let magnitude = value.unsigned_abs_u128();
+                BigInt::from_parts(value < 0, vec![magnitude as u64, (magnitude >> 64) as u64])
+            }
+        }
+    )*};
+}
+
+/// Magnitude of a primitive integer, widened to `u128`
+trait UnsignedAbsU128 {
+    fn unsigned_abs_u128(self) -> u128;
+}
+
+macro_rules! unsigned_abs_u128 {
+    (signed: $($t:ty)*) => {$(
+        impl UnsignedAbsU128 for $t {
+            fn unsigned_abs_u128(self) -> u128 {
+                self.unsigned_abs() as u128
+            }
+        }
+    )*};
+    (unsigned: $($t:ty)*) => {$(
+        impl UnsignedAbsU128 for $t {
+            fn unsigned_abs_u128(self) -> u128 {
+                self as u128
+            }
+        }
+    )*};
+}
+
+unsigned_abs_u128!(signed: i8 i16 i32 i64 i128 isize);
+unsigned_abs_u128!(unsigned: u8 u16 u32 u64 u128 usize);
+big_int_from_primitive!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl std::fmt::Display for BigInt {
+    /// Print in decimal, honouring the sign flag and width of the formatter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// let value: BigInt = "-123456789012345678901234567890".parse().unwrap();
+    ///
+    /// assert_eq!(value.to_string(), "-123456789012345678901234567890");
+    /// assert_eq!(format!("{:+}", BigInt::from(5)), "+5");
+    /// assert_eq!(format!("{:>4}", BigInt::from(5)), "   5");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(
+            !self.negative,
+            "",
+            &radix::to_str_radix(&self.magnitude, 10),
+        )
+    }
+}
+
+impl std::fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Implement a formatting trait printing the magnitude in a power-of-two radix
+macro_rules! big_int_radix_format {
+    ($trait:ident, $radix:expr, $prefix:expr, $uppercase:expr) => {
+        impl std::fmt::$trait for BigInt {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let digits = radix::to_str_radix(&self.magnitude, $radix);
+                let digits = if $uppercase {
+                    digits.to_uppercase()
+                } else {
+                    digits
+                };
+                f.pad_integral(!self.negative, $prefix, &digits)
+            }
+        }
+    };
+}
+
+big_int_radix_format!(Binary, 2, "0b", false);
+big_int_radix_format!(Octal, 8, "0o", false);
+big_int_radix_format!(LowerHex, 16, "0x", false);
+big_int_radix_format!(UpperHex, 16, "0x", true);
+
+impl std::str::FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Parse a decimal integer with an optional sign
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::{BigInt, ParseBigIntError};
+    ///
+    /// assert_eq!("+42".parse(), Ok(BigInt::from(42)));
+    /// assert_eq!("-0".parse(), Ok(BigInt::from(0)));
+    /// assert_eq!("".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+    /// assert_eq!("12a".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <BigInt as num_traits::Num>::from_str_radix(s, 10)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => magnitude::cmp(&self.magnitude, &other.magnitude),
+            (true, true) => magnitude::cmp(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl<'a> Add<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, rhs.negative)
+    }
+}
+
+impl<'a> Sub<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, !rhs.negative && !rhs.magnitude.is_empty())
+    }
+}
+
+impl<'a> Mul<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::BigInt;
+    ///
+    /// let a = BigInt::from(3).pow(5000);
+    /// let b = BigInt::from(-7).pow(3001);
+    ///
+    /// assert_eq!(&(&a * &b) / &b, a);
+    /// assert_eq!(&(&a * &b) % &a, BigInt::from(0));
+    /// ```
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            magnitude::mul(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl<'a> Div<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.checked_div_rem(rhs)
+            .expect("attempt to divide by zero")
+            .0
+    }
+}
+
+impl<'a> Rem<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.checked_div_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+            .1
+    }
+}
+
+/// Implement an operator for owned operands and its assignment variants
+/// on top of the implementation for borrowed operands
+macro_rules! big_int_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<'a> $trait<&'a BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: &'a BigInt) -> BigInt {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $assign_trait for BigInt {
+            fn $assign_method(&mut self, rhs: BigInt) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a BigInt> for BigInt {
+            fn $assign_method(&mut self, rhs: &'a BigInt) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+big_int_operator!(Add, add, AddAssign, add_assign);
+big_int_operator!(Sub, sub, SubAssign, sub_assign);
+big_int_operator!(Mul, mul, MulAssign, mul_assign);
+big_int_operator!(Div, div, DivAssign, div_assign);
+big_int_operator!(Rem, rem, RemAssign, rem_assign);
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for BigInt {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedMul for BigInt {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl CheckedDiv for BigInt {
+    /// [None] on division by zero
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+}
+
+impl num_traits::Num for BigInt {
+    type FromStrRadixErr = ParseBigIntError;
+
+    /// Parse an integer with an optional sign in a radix from 2 to 36
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside of 2 to 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Num;
+    /// use smsflib::number::BigInt;
+    ///
+    /// let value = BigInt::from_str_radix("-FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 16).unwrap();
+    ///
+    /// assert_eq!(value, -BigInt::from(u128::MAX));
+    /// assert_eq!(BigInt::from_str_radix("zz", 36), Ok(BigInt::from(1295)));
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        let magnitude =
+            radix::from_str_radix(digits, radix).ok_or(ParseBigIntError::InvalidDigit)?;
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
+
+impl num_traits::Signed for BigInt {
+    fn abs(&self) -> Self {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            BigInt::zero()
+        } else {
+            self - other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        match (self.negative, self.is_zero()) {
+            (_, true) => BigInt::zero(),
+            (true, false) => BigInt::from(-1),
+            (false, false) => BigInt::one(),
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+impl num_traits::Pow<u32> for BigInt {
+    type Output = BigInt;
+
+    fn pow(self, exponent: u32) -> BigInt {
+        BigInt::pow(&self, exponent)
+    }
+}
+
+impl num_traits::FromPrimitive for BigInt {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    /// Convert exactly, [None] if the value is not an integer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::FromPrimitive;
+    /// use smsflib::number::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_f64(-1e30), "-1000000000000000019884624838656".parse().ok());
+    /// assert_eq!(BigInt::from_f64(0.5), None);
+    /// ```
+    fn from_f64(n: f64) -> Option<Self> {
+        if !n.is_finite() || n.fract() != 0.0 {
+            return None;
+        }
+        let (mantissa, exponent, sign) = num_traits::Float::integer_decode(n);
+        let value = if exponent >= 0 {
+            BigInt::from(mantissa) * BigInt::from(2).pow(u32::from(exponent.unsigned_abs()))
+        } else {
+            BigInt::from(mantissa >> exponent.unsigned_abs().min(63))
+        };
+        Some(if sign < 0 { -value } else { value })
+    }
+}
+
+impl ToPrimitive for BigInt {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|value| value.to_i64())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|value| value.to_u64())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            self.magnitude_u128()
+        }
+    }
+
+    /// Nearest `f64`, infinite if the magnitude is too large
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::ToPrimitive;
+    /// use smsflib::number::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(10).pow(30).to_f64(), Some(1e30));
+    /// assert_eq!((-BigInt::from(10).pow(400)).to_f64(), Some(f64::NEG_INFINITY));
+    /// ```
+    fn to_f64(&self) -> Option<f64> {
+        // Parse the decimal digits, which rounds correctly
+        self.to_string().parse().ok()
+    }
+}
+
+impl BigInt {
+    /// Magnitude as `u128`, [None] if it does not fit
+    fn magnitude_u128(&self) -> Option<u128> {
+        match self.magnitude[..] {
+            [] => Some(0),
+            [low] => Some(u128::from(low)),
+            [low, high] => Some(u128::from(low) | (u128::from(high) << 64)),
+            _ => None,
+        }
+    }
+}
+
+/// # Note
+/// Results never overflow, so all policies return the exact result and only division by zero
+/// returns [None].
+impl OverflowArithmetic for BigInt {
+    fn add_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    fn sub_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+
+    fn mul_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    fn div_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    fn neg_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(-self)
+    }
+
+    fn abs_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(num_traits::Signed::abs(self))
+    }
+}
@@ -0,0 +1,99 @@
+//! Conversion of magnitudes from and to digit strings.
+//!
+//! Both directions work on chunks of as many digits as fit into one limb, so that a
+//! conversion costs one limb multiplication or division per chunk and limb.
+
+use super::magnitude;
+
+/// Largest power of `radix` fitting into a limb and its number of digits
+fn chunk_base(radix: u32) -> (u64, usize) {
+    let radix = u64::from(radix);
+    let mut base = radix;
+    let mut num_digits = 1;
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        num_digits += 1;
+    }
+    (base, num_digits)
+}
+
+/// Format a magnitude with lowercase digits, "0" for zero
+pub(super) fn to_str_radix(limbs: &[u64], radix: u32) -> String {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in 2..=36, not {}",
+        radix
+    );
+    if limbs.is_empty() {
+        return "0".to_string();
+    }
+    let (base, num_digits) = chunk_base(radix);
+    let mut chunks = Vec::new();
+    let mut rest = limbs.to_vec();
+    while !rest.is_empty() {
+        let (quotient, chunk) = magnitude::div_rem_limb(&rest, base);
+        chunks.push(chunk);
+        rest = quotient;
+    }
+
+    let mut digits = String::with_capacity(chunks.len() * num_digits);
+    for (idx, &chunk) in chunks.iter().rev().enumerate() {
+        let chunk_digits = u64_to_str_radix(chunk, radix);
+        // All chunks but the most significant one keep their leading zeros
+        if idx > 0 {
+            digits.extend(std::iter::repeat_n('0', num_digits - chunk_digits.len()));
+        }
+        digits.push_str(&chunk_digits);
+    }
+    digits
+}
+
+fn u64_to_str_radix(mut value: u64, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        let digit = (value % u64::from(radix)) as u32;
+        digits.push(std::char::from_digit(digit, radix).unwrap());
+        value /= u64::from(radix);
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Parse a non-empty string of digits without sign, [None] if it contains other characters
+pub(super) fn from_str_radix(digits: &str, radix: u32) -> Option<Vec<u64>> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in 2..=36, not {}",
+        radix
+    );
+    if digits.is_empty() {
+        return None;
+    }
+    let (_, num_digits) = chunk_base(radix);
+    let digits: Vec<u32> = digits
+        .chars()
+        .map(|c| c.to_digit(radix))
+        .collect::<Option<_>>()?;
+
+    let mut limbs = Vec::new();
+    // The first chunk takes the remaining digits, so that all others are complete
+    let first_len = match digits.len() % num_digits {
+        0 => num_digits,
+        len => len,
+    };
+    let mut start = 0;
+    let mut len = first_len;
+    while start < digits.len() {
+        let chunk = &digits[start..start + len];
+        let value = chunk.iter().fold(0u64, |value, &digit| {
+            value * u64::from(radix) + u64::from(digit)
+        });
+        let scale = u64::from(radix).pow(len as u32);
+        magnitude::mul_limb_add_assign(&mut limbs, scale, value);
+        start += len;
+        len = num_digits;
+    }
+    Some(limbs)
+}
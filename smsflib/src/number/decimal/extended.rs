@@ -0,0 +1,218 @@
+//! Decimal numbers with more digits than the element type, for intermediate results.
+//!
+//! Values are `coefficient * 10^exponent` with a signed coefficient of any size. Operations
+//! taking a precision truncate their result to that many significant digits, the others are
+//! exact.
+
+use crate::number::BigInt;
+use num_traits::{Signed, Zero};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+/// `10^n`
+pub(super) fn pow10(n: u64) -> BigInt {
+    if n <= 38 {
+        BigInt::from(10u128.pow(n as u32))
+    } else {
+        BigInt::from(10).pow(u32::try_from(n).expect("power of ten too large"))
+    }
+}
+
+/// Number of decimal digits of the magnitude, zero for zero
+pub(super) fn num_digits(value: &BigInt) -> i64 {
+    let bits = value.bits();
+    if bits == 0 {
+        return 0;
+    }
+    // Values of `bits` bits have this many digits or one more, log10(2) = 0.30102999566...
+    let digits = (u128::from(bits - 1) * 30_102_999_566 / 100_000_000_000) as i64 + 1;
+    if value.abs() >= pow10(digits as u64) {
+        digits + 1
+    } else {
+        digits
+    }
+}
+
+/// Largest integer whose `n`-th power is at most `value`, for non-negative `value`
+pub(super) fn integer_root(value: &BigInt, n: u32) -> BigInt {
+    if value.is_zero() {
+        return BigInt::zero();
+    }
+    let n_big = BigInt::from(n);
+    // Newton's method decreases monotonically from any start above the root
+    let mut root = BigInt::from(2).pow(u32::try_from(value.bits().div_ceil(u64::from(n))).unwrap());
+    loop {
+        let next = (&(&root * &BigInt::from(n - 1)) + &(value / &root.pow(n - 1))) / &n_big;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Extended {
+    pub(super) coefficient: BigInt,
+    pub(super) exponent: i64,
+}
+
+impl Extended {
+    pub(super) fn new(coefficient: BigInt, exponent: i64) -> Self {
+        Extended {
+            coefficient,
+            exponent,
+        }
+    }
+
+    pub(super) fn from_int(value: i64) -> Self {
+        Extended::new(BigInt::from(value), 0)
+    }
+
+    pub(super) fn one() -> Self {
+        Extended::from_int(1)
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.coefficient.is_zero()
+    }
+
+    pub(super) fn is_negative(&self) -> bool {
+        self.coefficient.is_negative()
+    }
+
+    /// Exponent of the most significant digit, [i64::MIN] for zero
+    pub(super) fn adjusted(&self) -> i64 {
+        match num_digits(&self.coefficient) {
+            0 => i64::MIN,
+            digits => self.exponent + digits - 1,
+        }
+    }
+
+    pub(super) fn neg(&self) -> Self {
+        Extended::new(-&self.coefficient, self.exponent)
+    }
+
+    pub(super) fn abs(&self) -> Self {
+        Extended::new(self.coefficient.abs(), self.exponent)
+    }
+
+    /// Multiply by `10^n`
+    pub(super) fn scale(&self, n: i64) -> Self {
+        Extended::new(self.coefficient.clone(), self.exponent + n)
+    }
+
+    /// Exact half, as five tenths
+    pub(super) fn half(&self) -> Self {
+        Extended::new(&self.coefficient * &BigInt::from(5), self.exponent - 1)
+    }
+
+    /// Keep the `precision` most significant digits, truncating towards zero
+    pub(super) fn truncate(self, precision: u32) -> Self {
+        let excess = num_digits(&self.coefficient) - i64::from(precision);
+        if excess <= 0 {
+            return self;
+        }
+        Extended::new(
+            &self.coefficient / &pow10(excess as u64),
+            self.exponent + excess,
+        )
+    }
+
+    pub(super) fn add_exact(&self, rhs: &Self) -> Self {
+        let exponent = self.exponent.min(rhs.exponent);
+        let lhs_coefficient = &self.coefficient * &pow10((self.exponent - exponent) as u64);
+        let rhs_coefficient = &rhs.coefficient * &pow10((rhs.exponent - exponent) as u64);
+        Extended::new(&lhs_coefficient + &rhs_coefficient, exponent)
+    }
+
+    pub(super) fn sub_exact(&self, rhs: &Self) -> Self {
+        self.add_exact(&rhs.neg())
+    }
+
+    pub(super) fn mul_exact(&self, rhs: &Self) -> Self {
+        Extended::new(
+            &self.coefficient * &rhs.coefficient,
+            self.exponent + rhs.exponent,
+        )
+    }
+
+    pub(super) fn add(&self, rhs: &Self, precision: u32) -> Self {
+        let (large, small) = if self.adjusted() >= rhs.adjusted() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        if small.is_zero() || large.adjusted() - small.adjusted() > i64::from(precision) + 2 {
+            return large.clone().truncate(precision);
+        }
+        large.add_exact(small).truncate(precision)
+    }
+
+    pub(super) fn sub(&self, rhs: &Self, precision: u32) -> Self {
+        self.add(&rhs.neg(), precision)
+    }
+
+    pub(super) fn mul(&self, rhs: &Self, precision: u32) -> Self {
+        self.mul_exact(rhs).truncate(precision)
+    }
+
+    /// Quotient for a non-zero `rhs`
+    pub(super) fn div(&self, rhs: &Self, precision: u32) -> Self {
+        let shift = (i64::from(precision) + 1 + num_digits(&rhs.coefficient)
+            - num_digits(&self.coefficient))
+        .max(0);
+        let numerator = &self.coefficient * &pow10(shift as u64);
+        Extended::new(
+            &numerator / &rhs.coefficient,
+            self.exponent - shift - rhs.exponent,
+        )
+        .truncate(precision)
+    }
+
+    pub(super) fn mul_int(&self, rhs: i64, precision: u32) -> Self {
+        self.mul(&Extended::from_int(rhs), precision)
+    }
+
+    pub(super) fn div_int(&self, rhs: i64, precision: u32) -> Self {
+        self.div(&Extended::from_int(rhs), precision)
+    }
+
+    /// Square root of a non-negative value
+    pub(super) fn sqrt(&self, precision: u32) -> Self {
+        let mut shift = (2 * i64::from(precision) + 2 - num_digits(&self.coefficient)).max(0);
+        if (self.exponent - shift) % 2 != 0 {
+            shift += 1;
+        }
+        let radicand = &self.coefficient * &pow10(shift as u64);
+        Extended::new(integer_root(&radicand, 2), (self.exponent - shift) / 2).truncate(precision)
+    }
+
+    pub(super) fn cmp_abs(&self, rhs: &Self) -> Ordering {
+        match (self.is_zero(), rhs.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        self.adjusted().cmp(&rhs.adjusted()).then_with(|| {
+            self.abs()
+                .sub_exact(&rhs.abs())
+                .coefficient
+                .cmp(&BigInt::zero())
+        })
+    }
+
+    /// Nearest integer, halves rounded away from zero
+    pub(super) fn round_to_integer(&self) -> BigInt {
+        if self.exponent >= 0 {
+            return &self.coefficient * &pow10(self.exponent as u64);
+        }
+        let divisor = pow10(self.exponent.unsigned_abs());
+        let (quotient, remainder) = self.coefficient.checked_div_rem(&divisor).unwrap();
+        if &remainder.abs() * &BigInt::from(2) >= divisor {
+            &quotient + &self.coefficient.signum()
+        } else {
+            quotient
+        }
+    }
+}
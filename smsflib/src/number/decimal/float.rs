@@ -0,0 +1,807 @@
+use super::extended::{integer_root, num_digits, pow10, Extended};
+use super::functions;
+use super::{Class, Decimal, MAX_EXPONENT, MIN_EXPONENT};
+use crate::number::BigInt;
+use num_traits::{Float, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::num::FpCategory;
+
+/// Largest magnitude of arguments of `exp` whose result does not overflow or underflow,
+/// with some margin
+const EXP_LIMIT: i64 = 15_000;
+
+/// Whether `|x|` exceeds `limit`
+fn exceeds(x: &Extended, limit: i64) -> bool {
+    x.cmp_abs(&Extended::from_int(limit)) == Ordering::Greater
+}
+
+/// How to round to an integer
+#[derive(Clone, Copy)]
+enum Rounding {
+    Floor,
+    Ceil,
+    HalfAwayFromZero,
+    TowardsZero,
+}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    /// Digits kept in intermediate results of the transcendental functions
+    fn working_precision() -> u32 {
+        DIGITS + 4
+    }
+
+    /// Correctly rounded `n`-th root of an exact non-negative value
+    fn round_root(value: &Extended, n: u32) -> Self {
+        let n_digits = i64::from(n);
+        let mut shift =
+            (n_digits * (i64::from(DIGITS) + 1) - num_digits(&value.coefficient)).max(0);
+        shift += (value.exponent - shift).rem_euclid(n_digits);
+        let radicand = &value.coefficient * &pow10(shift as u64);
+        let root = integer_root(&radicand, n);
+        let inexact = root.pow(n) != radicand;
+        Self::round(false, root, (value.exponent - shift) / n_digits, inexact)
+    }
+
+    /// `numerator / denominator * π`
+    fn pi_fraction(numerator: i64, denominator: i64) -> Self {
+        let precision = Self::working_precision();
+        Self::round_extended(
+            &functions::pi(precision)
+                .mul_int(numerator, precision)
+                .div_int(denominator, precision),
+        )
+    }
+
+    fn round_to_integer(self, rounding: Rounding) -> Self {
+        if !self.is_finite_number() || self.exponent >= 0 {
+            return self;
+        }
+        let divisor = pow10(u64::from(self.exponent.unsigned_abs()));
+        let (quotient, remainder) = BigInt::from(self.coefficient)
+            .checked_div_rem(&divisor)
+            .unwrap();
+        let round_up = match rounding {
+            Rounding::Floor => self.negative,
+            Rounding::Ceil => !self.negative,
+            Rounding::HalfAwayFromZero => (&remainder + &remainder) >= divisor,
+            Rounding::TowardsZero => false,
+        };
+        // The remainder is never zero, as the coefficient has no trailing zeros
+        let magnitude = if round_up {
+            quotient + BigInt::one()
+        } else {
+            quotient
+        };
+        Self::round(self.negative, magnitude, 0, false)
+    }
+
+    /// Whether this is an odd integer
+    fn is_odd_integer(&self) -> bool {
+        self.is_finite_number() && self.exponent == 0 && self.coefficient % 2 == 1
+    }
+
+    /// Whether this is an integer
+    fn is_integer(&self) -> bool {
+        self.is_finite_number() && (self.exponent >= 0 || self.coefficient == 0)
+    }
+
+    /// `self^exponent` for a finite `self > 0` and a finite `exponent`
+    fn pow_positive(self, exponent: Self) -> Extended {
+        let precision = Self::working_precision();
+        let base = self.to_extended();
+        if exponent.is_integer() && exponent.abs() < Self::new(1, 9) {
+            return functions::powi(&base, &exponent.integer_part().unwrap(), precision);
+        }
+        let product =
+            functions::ln(&base, precision + 6).mul(&exponent.to_extended(), precision + 6);
+        if exceeds(&product, EXP_LIMIT) {
+            // Overflows or underflows
+            return Extended::new(
+                BigInt::one(),
+                if product.is_negative() { -2 } else { 2 } * EXP_LIMIT,
+            );
+        }
+        functions::exp(&product, precision)
+    }
+}
+
+impl<const DIGITS: u32> Float for Decimal<DIGITS> {
+    fn nan() -> Self {
+        Self::not_a_number()
+    }
+
+    fn infinity() -> Self {
+        Self::infinity_with_sign(false)
+    }
+
+    fn neg_infinity() -> Self {
+        Self::infinity_with_sign(true)
+    }
+
+    fn neg_zero() -> Self {
+        Self::zero_with_sign(true)
+    }
+
+    fn min_value() -> Self {
+        -Self::max_value()
+    }
+
+    /// 1e-6143, there are no subnormal numbers
+    fn min_positive_value() -> Self {
+        Self::new(1, MIN_EXPONENT as i32)
+    }
+
+    /// Difference between one and the next larger number
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::epsilon().to_string(), "1e-33");
+    /// assert_eq!(Decimal::<7>::epsilon().to_string(), "0.000001");
+    /// ```
+    fn epsilon() -> Self {
+        Self::new(1, 1 - DIGITS as i32)
+    }
+
+    fn max_value() -> Self {
+        Self::new(
+            10i128.pow(DIGITS) - 1,
+            (MAX_EXPONENT + 1 - i64::from(DIGITS)) as i32,
+        )
+    }
+
+    fn is_nan(self) -> bool {
+        self.class == Class::Nan
+    }
+
+    fn is_infinite(self) -> bool {
+        self.class == Class::Infinite
+    }
+
+    fn is_finite(self) -> bool {
+        self.is_finite_number()
+    }
+
+    fn is_normal(self) -> bool {
+        self.is_finite_number() && self.coefficient != 0
+    }
+
+    fn classify(self) -> FpCategory {
+        match self.class {
+            Class::Nan => FpCategory::Nan,
+            Class::Infinite => FpCategory::Infinite,
+            Class::Finite if self.coefficient == 0 => FpCategory::Zero,
+            Class::Finite => FpCategory::Normal,
+        }
+    }
+
+    fn floor(self) -> Self {
+        self.round_to_integer(Rounding::Floor)
+    }
+
+    fn ceil(self) -> Self {
+        self.round_to_integer(Rounding::Ceil)
+    }
+
+    /// Round half-way cases away from zero like `f64::round`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// let value: Decimal = "-2.5".parse().unwrap();
+    ///
+    /// assert_eq!(value.round().to_string(), "-3");
+    /// assert_eq!(value.floor().to_string(), "-3");
+    /// assert_eq!(value.ceil().to_string(), "-2");
+    /// assert_eq!(value.trunc().to_string(), "-2");
+    /// assert_eq!(value.fract().to_string(), "-0.5");
+    /// ```
+    fn round(self) -> Self {
+        self.round_to_integer(Rounding::HalfAwayFromZero)
+    }
+
+    fn trunc(self) -> Self {
+        self.round_to_integer(Rounding::TowardsZero)
+    }
+
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    fn abs(self) -> Self {
+        Decimal {
+            negative: false,
+            ..self
+        }
+    }
+
+    fn signum(self) -> Self {
+        match self.class {
+            Class::Nan => self,
+            _ if self.negative => -Self::one(),
+            _ => Self::one(),
+        }
+    }
+
+    fn is_sign_positive(self) -> bool {
+        !self.negative
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.negative
+    }
+
+    /// `self * a + b` with a single rounding
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// let third = Decimal::<34>::new(1, 0) / Decimal::new(3, 0);
+    ///
+    /// assert_eq!(third.mul_add(Decimal::new(3, 0), Decimal::new(-1, 0)).to_string(), "-1e-34");
+    /// ```
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        if !(self.is_finite_number() && a.is_finite_number() && b.is_finite_number())
+            || self.is_zero()
+            || a.is_zero()
+        {
+            return self * a + b;
+        }
+        let product = self.to_extended().mul_exact(&a.to_extended());
+        if b.is_zero() {
+            Self::round_extended(&product)
+        } else {
+            Self::round_sum(&product, &b.to_extended())
+        }
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.powf(Self::new(i128::from(n), 0))
+    }
+
+    /// Power with the special cases of `f64::powf`
+    ///
+    /// Integer exponents below 10^9 are calculated by repeated multiplication, so that
+    /// exact powers are exact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// let value: Decimal = "1.1".parse().unwrap();
+    ///
+    /// assert_eq!(value.powi(3).to_string(), "1.331");
+    /// assert_eq!(Decimal::<34>::new(2, 0).powf(Decimal::new(5, -1)).to_string(), "1.414213562373095048801688724209698");
+    /// assert!(Decimal::<34>::new(-8, 0).powf(Decimal::new(5, -1)).is_nan());
+    /// ```
+    fn powf(self, n: Self) -> Self {
+        if n.is_zero() || self == Self::one() {
+            return Self::one();
+        }
+        if self.is_nan() || n.is_nan() {
+            return Self::not_a_number();
+        }
+        let odd = n.is_odd_integer();
+        if self.is_zero() {
+            return match (n.negative, odd) {
+                (true, true) => Self::infinity_with_sign(self.negative),
+                (true, false) => Self::infinity(),
+                (false, true) => self,
+                (false, false) => Self::zero(),
+            };
+        }
+        if n.is_infinite() {
+            return match self.abs().partial_cmp(&Self::one()) {
+                Some(Ordering::Equal) => Self::one(),
+                Some(Ordering::Less) if n.negative => Self::infinity(),
+                Some(Ordering::Less) => Self::zero(),
+                _ if n.negative => Self::zero(),
+                _ => Self::infinity(),
+            };
+        }
+        if self.is_infinite() {
+            return match (n.negative, odd) {
+                (true, true) => Self::zero_with_sign(self.negative),
+                (true, false) => Self::zero(),
+                (false, true) => self,
+                (false, false) => Self::infinity(),
+            };
+        }
+        if self.negative && !n.is_integer() {
+            return Self::not_a_number();
+        }
+        let result = Self::round_extended(&self.abs().pow_positive(n));
+        if self.negative && odd {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Correctly rounded square root
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(2, 0).sqrt().to_string(), "1.414213562373095048801688724209698");
+    /// assert_eq!(Decimal::<34>::new(144, -4).sqrt().to_string(), "0.12");
+    /// assert!(Decimal::<34>::new(-1, 0).sqrt().is_nan());
+    /// ```
+    fn sqrt(self) -> Self {
+        match self.class {
+            _ if self.is_zero() => self,
+            Class::Nan => self,
+            _ if self.negative => Self::not_a_number(),
+            Class::Infinite => self,
+            Class::Finite => Self::round_root(&self.to_extended(), 2),
+        }
+    }
+
+    fn exp(self) -> Self {
+        match self.class {
+            Class::Nan => self,
+            Class::Infinite if self.negative => Self::zero(),
+            Class::Infinite => self,
+            Class::Finite => {
+                let x = self.to_extended();
+                if exceeds(&x, EXP_LIMIT) {
+                    if self.negative {
+                        Self::zero()
+                    } else {
+                        Self::infinity()
+                    }
+                } else {
+                    Self::round_extended(&functions::exp(&x, Self::working_precision()))
+                }
+            }
+        }
+    }
+
+    fn exp2(self) -> Self {
+        Self::new(2, 0).powf(self)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(10, 0).ln().to_string(), "2.302585092994045684017991454684364");
+    /// assert_eq!(Decimal::<34>::new(1, -6000).ln().to_string(), "-13815.51055796427410410794872810619");
+    /// assert_eq!(Decimal::<34>::new(0, 0).ln(), Decimal::neg_infinity());
+    /// ```
+    fn ln(self) -> Self {
+        self.logarithm(functions::ln)
+    }
+
+    fn log(self, base: Self) -> Self {
+        let is_regular = |value: Self| {
+            value.is_finite_number() && !value.negative && !value.is_zero() && value != Self::one()
+        };
+        if !is_regular(self) || !is_regular(base) {
+            return self.ln() / base.ln();
+        }
+        let precision = Self::working_precision() + 2;
+        let ln_self = functions::ln(&self.to_extended(), precision);
+        let ln_base = functions::ln(&base.to_extended(), precision);
+        Self::round_extended(&ln_self.div(&ln_base, precision))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(1024, 0).log2().to_string(), "10");
+    /// ```
+    fn log2(self) -> Self {
+        self.logarithm(functions::log2)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(1, -20).log10().to_string(), "-20");
+    /// assert_eq!(Decimal::<34>::new(2, 0).log10().to_string(), "0.301029995663981195213738894724493");
+    /// ```
+    fn log10(self) -> Self {
+        self.logarithm(functions::log10)
+    }
+
+    fn max(self, other: Self) -> Self {
+        match self.partial_cmp(&other) {
+            _ if self.is_nan() => other,
+            Some(Ordering::Less) => other,
+            _ => self,
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        match self.partial_cmp(&other) {
+            _ if self.is_nan() => other,
+            Some(Ordering::Greater) => other,
+            _ => self,
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            Self::not_a_number()
+        } else if self <= other {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+
+    /// Correctly rounded real cube root
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(-27, -3).cbrt().to_string(), "-0.3");
+    /// assert_eq!(Decimal::<34>::new(2, 0).cbrt().to_string(), "1.259921049894873164767210607278228");
+    /// ```
+    fn cbrt(self) -> Self {
+        match self.class {
+            Class::Finite if !self.is_zero() => {
+                let root = Self::round_root(&self.abs().to_extended(), 3);
+                if self.negative {
+                    -root
+                } else {
+                    root
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// Correctly rounded `sqrt(self^2 + other^2)`
+    fn hypot(self, other: Self) -> Self {
+        if self.is_infinite() || other.is_infinite() {
+            return Self::infinity();
+        }
+        if self.is_nan() || other.is_nan() {
+            return Self::not_a_number();
+        }
+        let (large, small) = match self.cmp_abs(&other) {
+            Ordering::Less => (other.abs(), self.abs()),
+            _ => (self.abs(), other.abs()),
+        };
+        if small.is_zero() {
+            return large;
+        }
+        let large = large.to_extended();
+        let small = small.to_extended();
+        if small.adjusted() < large.adjusted() - i64::from(DIGITS) - 2 {
+            // The smaller square is below the rounding error
+            return Self::round_extended(&large);
+        }
+        let sum = large.mul_exact(&large).add_exact(&small.mul_exact(&small));
+        Self::round_root(&sum, 2)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// let pi = Decimal::<34>::new(-1, 0).acos();
+    ///
+    /// assert_eq!((pi / Decimal::new(6, 0)).sin().to_string(), "0.5");
+    /// assert_eq!(Decimal::<34>::new(1, 22).sin().to_string(), "-0.8522008497671888017727058937530294");
+    /// ```
+    fn sin(self) -> Self {
+        self.trigonometric(|sin, _, _| sin.clone())
+    }
+
+    fn cos(self) -> Self {
+        if self.is_zero() {
+            return Self::one();
+        }
+        self.trigonometric(|_, cos, _| cos.clone())
+    }
+
+    fn tan(self) -> Self {
+        self.trigonometric(|sin, cos, precision| sin.div(cos, precision))
+    }
+
+    fn asin(self) -> Self {
+        self.inverse_sine(false)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(-1, 0).acos().to_string(), "3.141592653589793238462643383279503");
+    /// assert_eq!(Decimal::<34>::new(1, 0).acos().to_string(), "0");
+    /// ```
+    fn acos(self) -> Self {
+        self.inverse_sine(true)
+    }
+
+    fn atan(self) -> Self {
+        self.atan2(Self::one())
+    }
+
+    /// Angle of the point (other, self) with the special cases of `f64::atan2`
+    fn atan2(self, other: Self) -> Self {
+        let with_sign = |value: Self| if self.negative { -value } else { value };
+        match (self.class, other.class) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::not_a_number(),
+            (Class::Infinite, Class::Infinite) if other.negative => {
+                with_sign(Self::pi_fraction(3, 4))
+            }
+            (Class::Infinite, Class::Infinite) => with_sign(Self::pi_fraction(1, 4)),
+            (Class::Infinite, _) => with_sign(Self::pi_fraction(1, 2)),
+            (_, Class::Infinite) if other.negative => with_sign(Self::pi_fraction(1, 1)),
+            (_, Class::Infinite) => with_sign(Self::zero()),
+            _ if self.is_zero() && other.negative => with_sign(Self::pi_fraction(1, 1)),
+            _ if self.is_zero() => self,
+            _ => Self::round_extended(&functions::atan2(
+                &self.to_extended(),
+                &other.to_extended(),
+                Self::working_precision(),
+            )),
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        match self.class {
+            Class::Nan => self,
+            Class::Infinite if self.negative => -Self::one(),
+            Class::Infinite => self,
+            Class::Finite if self.is_zero() => self,
+            Class::Finite => {
+                let x = self.to_extended();
+                if exceeds(&x, EXP_LIMIT) {
+                    if self.negative {
+                        -Self::one()
+                    } else {
+                        Self::infinity()
+                    }
+                } else {
+                    Self::round_extended(&functions::exp_m1(&x, Self::working_precision()))
+                }
+            }
+        }
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Float;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(1, -40).ln_1p().to_string(), "1e-40");
+    /// ```
+    fn ln_1p(self) -> Self {
+        let minus_one = -Self::one();
+        match self.class {
+            Class::Finite if self.is_zero() => self,
+            Class::Finite if self == minus_one => Self::neg_infinity(),
+            Class::Finite if self > minus_one => Self::round_extended(&functions::ln_1p(
+                &self.to_extended(),
+                Self::working_precision(),
+            )),
+            Class::Infinite if !self.negative => self,
+            _ => Self::not_a_number(),
+        }
+    }
+
+    fn sinh(self) -> Self {
+        self.hyperbolic(|x, precision| {
+            if x.adjusted() < 0 {
+                // sinh(x) = u (u + 2) / (2 (u + 1)) with u = exp(x) - 1
+                let u = functions::exp_m1(x, precision);
+                let one = Extended::one();
+                u.mul(&u.add(&Extended::from_int(2), precision), precision)
+                    .div(&u.add(&one, precision).mul_int(2, precision), precision)
+            } else {
+                let e = functions::exp(x, precision);
+                e.sub(&Extended::one().div(&e, precision), precision).half()
+            }
+        })
+    }
+
+    fn cosh(self) -> Self {
+        match self.class {
+            Class::Nan => self,
+            _ if self.is_zero() => Self::one(),
+            _ => self.abs().hyperbolic(|x, precision| {
+                let e = functions::exp(x, precision);
+                e.add(&Extended::one().div(&e, precision), precision).half()
+            }),
+        }
+    }
+
+    fn tanh(self) -> Self {
+        if self.abs() > Self::new(100, 0) {
+            return if self.negative {
+                -Self::one()
+            } else {
+                Self::one()
+            };
+        }
+        self.hyperbolic(|x, precision| {
+            // tanh(x) = u / (u + 2) with u = exp(2x) - 1
+            let u = functions::exp_m1(&x.mul_int(2, precision), precision);
+            u.div(&u.add(&Extended::from_int(2), precision), precision)
+        })
+    }
+
+    fn asinh(self) -> Self {
+        if !self.is_finite_number() || self.is_zero() {
+            return self;
+        }
+        let precision = Self::working_precision() + 2;
+        let x = self.abs().to_extended();
+        // asinh(x) = ln(1 + x + x^2 / (1 + sqrt(1 + x^2))) for x > 0
+        let x_squared = x.mul_exact(&x);
+        let root = Extended::one().add(&x_squared, precision).sqrt(precision);
+        let argument = x.add(
+            &x_squared.div(&Extended::one().add(&root, precision), precision),
+            precision,
+        );
+        let result = Self::round_extended(&functions::ln_1p(&argument, precision));
+        if self.negative {
+            -result
+        } else {
+            result
+        }
+    }
+
+    fn acosh(self) -> Self {
+        match self.partial_cmp(&Self::one()) {
+            Some(Ordering::Equal) => Self::zero(),
+            Some(Ordering::Greater) if self.is_infinite() => self,
+            Some(Ordering::Greater) => {
+                let precision = Self::working_precision() + 2;
+                // acosh(x) = ln(1 + d + sqrt(d (d + 2))) with d = x - 1
+                let d = self.to_extended().sub_exact(&Extended::one());
+                let root = d
+                    .mul_exact(&d.add_exact(&Extended::from_int(2)))
+                    .sqrt(precision);
+                Self::round_extended(&functions::ln_1p(&d.add(&root, precision), precision))
+            }
+            _ => Self::not_a_number(),
+        }
+    }
+
+    fn atanh(self) -> Self {
+        match self.abs().partial_cmp(&Self::one()) {
+            _ if self.is_zero() => self,
+            Some(Ordering::Equal) => Self::infinity_with_sign(self.negative),
+            Some(Ordering::Less) => {
+                let precision = Self::working_precision() + 2;
+                let x = self.to_extended();
+                let denominator = Extended::one().sub_exact(&x);
+                let logarithm =
+                    if x.abs().cmp_abs(&Extended::new(BigInt::from(5), -1)) == Ordering::Less {
+                        // atanh(x) = ln(1 + 2x / (1 - x)) / 2, accurate for small x
+                        let argument = x.mul_int(2, precision).div(&denominator, precision);
+                        functions::ln_1p(&argument, precision)
+                    } else {
+                        // atanh(x) = ln((1 + x) / (1 - x)) / 2, with exact differences near one
+                        let argument = Extended::one().add_exact(&x).div(&denominator, precision);
+                        functions::ln(&argument, precision)
+                    };
+                Self::round_extended(&logarithm.half())
+            }
+            _ => Self::not_a_number(),
+        }
+    }
+
+    /// Decomposition of the nearest `f64`
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.to_f64().unwrap_or(f64::NAN).integer_decode()
+    }
+}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    /// Logarithm of positive finite values, with the special cases of `f64::ln`
+    fn logarithm(self, kernel: impl FnOnce(&Extended, u32) -> Extended) -> Self {
+        match self.class {
+            Class::Nan => self,
+            _ if self.is_zero() => Self::neg_infinity(),
+            _ if self.negative => Self::not_a_number(),
+            Class::Infinite => self,
+            Class::Finite => {
+                Self::round_extended(&kernel(&self.to_extended(), Self::working_precision()))
+            }
+        }
+    }
+
+    /// Trigonometric function of finite values, NaN for infinite ones
+    fn trigonometric(self, kernel: impl FnOnce(&Extended, &Extended, u32) -> Extended) -> Self {
+        match self.class {
+            Class::Finite if self.is_zero() => self,
+            Class::Finite => {
+                let precision = Self::working_precision() + 2;
+                let (sin, cos) = functions::sin_cos(&self.to_extended(), precision);
+                Self::round_extended(&kernel(&sin, &cos, precision))
+            }
+            _ => Self::not_a_number(),
+        }
+    }
+
+    /// `asin` or `acos` as angle of the point (sqrt(1 - x^2), x)
+    fn inverse_sine(self, cosine: bool) -> Self {
+        match self.abs().partial_cmp(&Self::one()) {
+            _ if self.is_zero() && !cosine => self,
+            Some(Ordering::Less) | Some(Ordering::Equal) => {
+                let precision = Self::working_precision() + 2;
+                let x = self.to_extended();
+                let one = Extended::one();
+                let other = one
+                    .sub_exact(&x)
+                    .mul_exact(&one.add_exact(&x))
+                    .sqrt(precision);
+                let (y, x) = if cosine { (other, x) } else { (x, other) };
+                if y.is_zero() && x.is_negative() {
+                    Self::pi_fraction(1, 1)
+                } else if y.is_zero() {
+                    Self::zero()
+                } else {
+                    Self::round_extended(&functions::atan2(&y, &x, precision))
+                }
+            }
+            _ => Self::not_a_number(),
+        }
+    }
+
+    /// Odd hyperbolic function of finite non-zero values, rounded with the sign of `self`
+    fn hyperbolic(self, kernel: impl FnOnce(&Extended, u32) -> Extended) -> Self {
+        match self.class {
+            Class::Finite if self.is_zero() => self,
+            Class::Finite => {
+                let x = self.abs().to_extended();
+                let result = if exceeds(&x, EXP_LIMIT) {
+                    Self::infinity()
+                } else {
+                    Self::round_extended(&kernel(&x, Self::working_precision() + 2))
+                };
+                if self.negative {
+                    -result
+                } else {
+                    result
+                }
+            }
+            _ => self,
+        }
+    }
+}
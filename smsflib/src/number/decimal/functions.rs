@@ -0,0 +1,331 @@
+//! Transcendental functions on [Extended] values.
+//!
+//! Every function takes the number of significant digits to keep in intermediate results
+//! and adds its own guard digits where argument reduction loses some. Arguments are reduced
+//! into small intervals around zero, where Taylor series converge quickly:
+//!
+//! * `exp` splits off a power of ten, halves the rest ten times and squares back,
+//! * `ln` splits off powers of ten and two and uses the series of `atanh`,
+//! * `sin` and `cos` subtract a multiple of π/2 with π to as many digits as needed,
+//! * `atan` halves the angle twice with the half-angle formula.
+
+use super::extended::{pow10, Extended};
+use crate::number::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+
+/// Digits added to the precision of arguments and constants of the reductions
+const GUARD_DIGITS: u32 = 8;
+
+/// Number of halvings of the argument of `exp_m1`
+const EXP_HALVINGS: u32 = 10;
+
+/// Sum `first_term * ∏ factor(n)` over n = 1, 2, ... until the terms vanish in the precision
+fn sum_series(
+    first_term: Extended,
+    precision: u32,
+    mut factor: impl FnMut(&Extended, i64) -> Extended,
+) -> Extended {
+    let mut sum = first_term.clone();
+    let mut term = first_term;
+    for n in 1.. {
+        term = factor(&term, n);
+        if term.is_zero()
+            || term.adjusted() < sum.adjusted().saturating_sub(i64::from(precision) + 1)
+        {
+            break;
+        }
+        sum = sum.add(&term, precision);
+    }
+    sum
+}
+
+/// `atanh(z)` for `|z| <= 0.2` as `z + z^3/3 + z^5/5 + ...`
+fn atanh_series(z: &Extended, precision: u32) -> Extended {
+    let z_squared = z.mul(z, precision);
+    let mut power = z.clone();
+    sum_series(z.clone(), precision, |_, n| {
+        power = power.mul(&z_squared, precision);
+        power.div_int(2 * n + 1, precision)
+    })
+}
+
+/// `atan(z)` for `|z| <= 0.2` as `z - z^3/3 + z^5/5 - ...`
+fn atan_series(z: &Extended, precision: u32) -> Extended {
+    let z_squared = z.mul(z, precision).neg();
+    let mut power = z.clone();
+    sum_series(z.clone(), precision, |_, n| {
+        power = power.mul(&z_squared, precision);
+        power.div_int(2 * n + 1, precision)
+    })
+}
+
+/// `exp(z) - 1` for `|z| <= 0.002` as `z + z^2/2! + z^3/3! + ...`
+fn exp_m1_series(z: &Extended, precision: u32) -> Extended {
+    sum_series(z.clone(), precision, |term, n| {
+        term.mul(z, precision).div_int(n + 1, precision)
+    })
+}
+
+/// `sin(r)` for `|r| <= π/4` as `r - r^3/3! + r^5/5! - ...`
+fn sin_series(r: &Extended, precision: u32) -> Extended {
+    let r_squared = r.mul(r, precision).neg();
+    sum_series(r.clone(), precision, |term, n| {
+        term.mul(&r_squared, precision)
+            .div_int((2 * n) * (2 * n + 1), precision)
+    })
+}
+
+/// `cos(r)` for `|r| <= π/4` as `1 - r^2/2! + r^4/4! - ...`
+fn cos_series(r: &Extended, precision: u32) -> Extended {
+    let r_squared = r.mul(r, precision).neg();
+    sum_series(Extended::one(), precision, |term, n| {
+        term.mul(&r_squared, precision)
+            .div_int((2 * n - 1) * (2 * n), precision)
+    })
+}
+
+/// `Σ (±1)^k / ((2k + 1) n^(2k + 1))`, i.e. `atan(1/n)` with alternating signs and
+/// `atanh(1/n)` without, in fixed point as every term only needs divisions by small integers
+fn inverse_series(n: u32, alternating: bool, precision: u32) -> Extended {
+    // Every term is truncated, so the error grows with the number of terms
+    let scale =
+        precision + GUARD_DIGITS + integer_digits(&Extended::from_int(i64::from(precision)));
+    let n_squared = BigInt::from(n * n);
+    let mut power = &pow10(u64::from(scale)) / &BigInt::from(n);
+    let mut sum = BigInt::from(0);
+    let mut k = 0;
+    while !power.is_zero() {
+        let term = &power / &BigInt::from(2 * k + 1);
+        if alternating && k % 2 == 1 {
+            sum -= term;
+        } else {
+            sum += term;
+        }
+        power = &power / &n_squared;
+        k += 1;
+    }
+    Extended::new(sum, -i64::from(scale)).truncate(precision)
+}
+
+/// π by Machin's formula π = 16 atan(1/5) - 4 atan(1/239)
+pub(super) fn pi(precision: u32) -> Extended {
+    let precision = precision + 2;
+    inverse_series(5, true, precision)
+        .mul_int(16, precision)
+        .sub(
+            &inverse_series(239, true, precision).mul_int(4, precision),
+            precision,
+        )
+}
+
+/// ln(2) = 2 atanh(1/3)
+fn ln2(precision: u32) -> Extended {
+    let precision = precision + 2;
+    inverse_series(3, false, precision).mul_int(2, precision)
+}
+
+/// ln(10) = 3 ln(2) + ln(1.25) = 3 ln(2) + 2 atanh(1/9)
+fn ln10(precision: u32) -> Extended {
+    let precision = precision + 2;
+    ln2(precision).mul_int(3, precision).add(
+        &inverse_series(9, false, precision).mul_int(2, precision),
+        precision,
+    )
+}
+
+/// Number of digits of the integer part of `|x|`, zero below one
+fn integer_digits(x: &Extended) -> u32 {
+    if x.is_zero() {
+        0
+    } else {
+        (x.adjusted() + 1).clamp(0, i64::from(u32::MAX / 2)) as u32
+    }
+}
+
+/// `exp(x) - 1`, accurate relative to the result also for `x` close to zero
+///
+/// `|x|` must be at most about 15000.
+pub(super) fn exp_m1(x: &Extended, precision: u32) -> Extended {
+    if x.adjusted() >= 0 {
+        exp(x, precision).sub(&Extended::one(), precision)
+    } else {
+        exp_m1_reduced(x, precision)
+    }
+}
+
+/// `exp(x) - 1` for `|x| <= 2` by halving the argument
+fn exp_m1_reduced(x: &Extended, precision: u32) -> Extended {
+    // exp(2z) - 1 = (exp(z) - 1) (exp(z) - 1 + 2) keeps the relative accuracy
+    let precision = precision + GUARD_DIGITS;
+    // 1 / 2^10 = 0.0009765625 exactly
+    let mut result = exp_m1_series(
+        &x.mul(&Extended::new(BigInt::from(9_765_625), -10), precision),
+        precision,
+    );
+    for _ in 0..EXP_HALVINGS {
+        result = result.mul(&result.add(&Extended::from_int(2), precision), precision);
+    }
+    result
+}
+
+/// `exp(x)` for `|x|` at most about 15000
+pub(super) fn exp(x: &Extended, precision: u32) -> Extended {
+    if x.is_zero() {
+        return Extended::one();
+    }
+    // exp(x) = exp(r) 10^k with |r| <= ln(10) / 2
+    let reduction_precision = precision + GUARD_DIGITS + integer_digits(x);
+    let ln10 = ln10(reduction_precision);
+    let k = x.div(&ln10, reduction_precision).round_to_integer();
+    let r = x.sub(
+        &ln10.mul(&Extended::new(k.clone(), 0), reduction_precision),
+        reduction_precision,
+    );
+    let k = k.to_i64().expect("argument of exp out of range");
+    exp_m1_reduced(&r, precision)
+        .add(&Extended::one(), precision + GUARD_DIGITS)
+        .scale(k)
+}
+
+/// `ln(1 + x)` for an exact `x > -1`, accurate relative to the result also for `x` close to zero
+pub(super) fn ln_1p(x: &Extended, precision: u32) -> Extended {
+    if x.cmp_abs(&Extended::new(BigInt::from(5), -1)) == Ordering::Less {
+        // ln(1 + x) = 2 atanh(x / (2 + x))
+        let precision = precision + GUARD_DIGITS;
+        let z = x.div(&x.add_exact(&Extended::from_int(2)), precision);
+        atanh_series(&z, precision).mul_int(2, precision)
+    } else {
+        ln(&x.add_exact(&Extended::one()), precision)
+    }
+}
+
+/// `ln(x)` for an exact `x > 0`
+pub(super) fn ln(x: &Extended, precision: u32) -> Extended {
+    let x_minus_1 = x.sub_exact(&Extended::one());
+    if x_minus_1.cmp_abs(&Extended::new(BigInt::from(5), -1)) == Ordering::Less {
+        return ln_1p(&x_minus_1, precision);
+    }
+    // x = m 2^j 10^t with 1 <= m < 1.5
+    let t = x.adjusted();
+    let mut m = x.scale(-t);
+    let mut j = 0;
+    while m.cmp_abs(&Extended::new(BigInt::from(15), -1)) != Ordering::Less {
+        m = m.half();
+        j += 1;
+    }
+    let precision = precision + GUARD_DIGITS;
+    let reduction_precision = precision + integer_digits(&Extended::from_int(t));
+    ln_1p(&m.sub_exact(&Extended::one()), precision)
+        .add(&ln2(precision).mul_int(j, precision), precision)
+        .add(
+            &ln10(reduction_precision).mul_int(t, reduction_precision),
+            precision,
+        )
+}
+
+/// `ln(x) / ln(10)` for an exact `x > 0`, exact for powers of ten
+pub(super) fn log10(x: &Extended, precision: u32) -> Extended {
+    let t = x.adjusted();
+    let precision = precision + GUARD_DIGITS;
+    let fraction = ln(&x.scale(-t), precision).div(&ln10(precision), precision);
+    Extended::from_int(t).add(&fraction, precision)
+}
+
+/// `ln(x) / ln(2)` for an exact `x > 0`
+pub(super) fn log2(x: &Extended, precision: u32) -> Extended {
+    let precision = precision + GUARD_DIGITS;
+    ln(x, precision).div(&ln2(precision), precision)
+}
+
+/// `(sin(x), cos(x))`
+pub(super) fn sin_cos(x: &Extended, precision: u32) -> (Extended, Extended) {
+    let precision = precision + GUARD_DIGITS;
+    if x.cmp_abs(&Extended::new(BigInt::from(78), -2)) == Ordering::Less {
+        return (sin_series(x, precision), cos_series(x, precision));
+    }
+    // x = r + k π/2 with |r| <= π/4, where r loses as many digits as x has integer digits
+    // and again as many as r has leading zeros
+    let mut extra_digits = 0;
+    let (k, r) = loop {
+        let reduction_precision = precision + integer_digits(x) + extra_digits;
+        let half_pi = pi(reduction_precision).half();
+        let k = x.div(&half_pi, reduction_precision).round_to_integer();
+        let r = x
+            .sub_exact(&half_pi.mul_exact(&Extended::new(k.clone(), 0)))
+            .truncate(precision);
+        let lost_digits = (-r.adjusted()).max(0) as u32;
+        if lost_digits <= extra_digits {
+            break (k, r);
+        }
+        extra_digits = lost_digits + GUARD_DIGITS;
+    };
+    let sin = sin_series(&r, precision);
+    let cos = cos_series(&r, precision);
+    let quadrant = (&k % &BigInt::from(4)).to_i64().unwrap().rem_euclid(4);
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, sin.neg()),
+        2 => (sin.neg(), cos.neg()),
+        _ => (cos.neg(), sin),
+    }
+}
+
+/// `atan(z)` for `|z| <= 1` by halving the angle twice with
+/// `atan(z) = 2 atan(z / (1 + sqrt(1 + z^2)))`
+fn atan_reduced(z: &Extended, precision: u32) -> Extended {
+    let mut z = z.clone();
+    for _ in 0..2 {
+        let hypotenuse = Extended::one()
+            .add(&z.mul(&z, precision), precision)
+            .sqrt(precision);
+        z = z.div(&Extended::one().add(&hypotenuse, precision), precision);
+    }
+    atan_series(&z, precision).mul_int(4, precision)
+}
+
+/// Angle of the point (x, y) in (-π, π] for non-zero `x` or `y`
+pub(super) fn atan2(y: &Extended, x: &Extended, precision: u32) -> Extended {
+    let precision = precision + GUARD_DIGITS;
+    if y.cmp_abs(x) != Ordering::Greater {
+        let angle = atan_reduced(&y.div(x, precision), precision);
+        if !x.is_negative() {
+            angle
+        } else if y.is_negative() {
+            angle.sub(&pi(precision), precision)
+        } else {
+            angle.add(&pi(precision), precision)
+        }
+    } else {
+        let angle = atan_reduced(&x.div(y, precision), precision);
+        let half_pi = pi(precision).half();
+        if y.is_negative() {
+            half_pi.neg().sub(&angle, precision)
+        } else {
+            half_pi.sub(&angle, precision)
+        }
+    }
+}
+
+/// `x^n` by repeated squaring for an integer `n`
+pub(super) fn powi(x: &Extended, n: &BigInt, precision: u32) -> Extended {
+    // Every squaring doubles the relative error
+    let precision = precision + GUARD_DIGITS + (n.bits() as u32) / 3 + 1;
+    let mut exponent = n.abs().to_u64().expect("integer exponent out of range");
+    let mut result = Extended::one();
+    let mut base = x.clone();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.mul(&base, precision);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.mul(&base, precision);
+        }
+    }
+    if n.is_negative() {
+        Extended::one().div(&result, precision)
+    } else {
+        result
+    }
+}
@@ -0,0 +1,998 @@
+/// Intermediate results with more digits than the element type
+mod extended;
+/// Implementation of `num_traits::Float`
+mod float;
+/// Transcendental functions on intermediate results
+mod functions;
+
+use super::{ArithmeticPolicy, BigInt, OverflowArithmetic};
+use extended::{num_digits, pow10, Extended};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Largest exponent of the most significant digit, larger results overflow to infinity
+const MAX_EXPONENT: i64 = 6144;
+/// Smallest exponent of the most significant digit, smaller results underflow to zero
+const MIN_EXPONENT: i64 = -6143;
+
+/// Decimal floating-point number with `DIGITS` significant digits.
+///
+/// Decimal fractions like 0.1 are exact, so sums of amounts do not drift like they do with
+/// binary floating-point numbers. Every operation rounds its result to `DIGITS` digits, halves
+/// to even. The default of 34 digits and the exponent range of ±6144 are those of IEEE 754
+/// decimal128, used by the DM42. `DIGITS` can be from 1 to 38, other values do not compile.
+///
+/// Numbers are finite, ±infinity or NaN with the semantics of `f64`, except that there are no
+/// subnormal numbers: results below 1e-6143 underflow to zero.
+///
+/// Stacks of decimals support the [FloatMathOperations](crate::stack::FloatMathOperations) in
+/// addition to the operations for other number types. Addition, subtraction, multiplication,
+/// division, remainder, `sqrt`, `cbrt`, `hypot` and `mul_add` are correctly rounded. The
+/// transcendental functions are calculated with guard digits and are accurate to about one
+/// unit in the last place. The [SpecialFunctions](crate::stack::SpecialFunctions) except for
+/// the hyperbolic functions have the accuracy of `f64` only.
+///
+/// # Example
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = DynamicSizedStack::<Decimal>::new();
+/// stack.push("0.1".parse().unwrap());
+/// stack.push("0.2".parse().unwrap());
+/// stack.add();
+///
+/// assert_eq!(stack.get(0).unwrap().to_string(), "0.3");
+///
+/// // A thousand payments of 0.01 sum up to 10 exactly
+/// for _ in 0..1000 {
+///     stack.push("0.01".parse().unwrap());
+///     stack.add();
+/// }
+/// assert_eq!(stack.get(0).unwrap().to_string(), "10.3");
+/// ```
+///
+/// Transcendental functions with 34 digits:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = DynamicSizedStack::<Decimal>::new();
+/// stack.push(Decimal::new(2, 0));
+/// stack.ln();
+/// assert_eq!(stack.get(0).unwrap().to_string(), "0.6931471805599453094172321214581766");
+///
+/// stack.push(Decimal::new(1, 0));
+/// stack.push(Decimal::new(1, 0));
+/// stack.atan2();
+/// stack.push(Decimal::new(4, 0));
+/// stack.multiply();
+/// assert_eq!(stack.get(0).unwrap().to_string(), "3.141592653589793238462643383279503");
+/// ```
+///
+/// Other precisions:
+///
+/// ```
+/// use smsflib::number::Decimal;
+///
+/// let third = Decimal::<7>::new(1, 0) / Decimal::<7>::new(3, 0);
+///
+/// assert_eq!(third.to_string(), "0.3333333");
+/// assert_eq!((third * Decimal::new(3, 0)).to_string(), "0.9999999");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Decimal<const DIGITS: u32 = 34> {
+    class: Class,
+    /// Also set for -0 and -∞
+    negative: bool,
+    /// Less than `10^DIGITS` without trailing zeros, zero for zero and the special values
+    coefficient: u128,
+    exponent: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Finite,
+    Infinite,
+    Nan,
+}
+
+/// Reason why a string could not be parsed into a [Decimal]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    /// The string has no digits
+    Empty,
+    /// The string is not a number, optionally with a radix point and an exponent
+    Invalid,
+}
+
+impl std::fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDecimalError::Empty => write!(f, "cannot parse decimal from empty string"),
+            ParseDecimalError::Invalid => write!(f, "invalid decimal literal"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    /// Fails to compile for unsupported precisions
+    const VALID_DIGITS: () = assert!(DIGITS >= 1 && DIGITS <= 38, "decimals have 1 to 38 digits");
+
+    /// Number of significant digits
+    pub const DIGITS: u32 = DIGITS;
+
+    /// `coefficient * 10^exponent`, rounded to `DIGITS` digits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::new(-15, -1).to_string(), "-1.5");
+    /// assert_eq!(Decimal::<3>::new(12345, -2).to_string(), "123");
+    /// assert_eq!(Decimal::<34>::new(1, 7000).to_string(), "inf");
+    /// ```
+    pub fn new(coefficient: i128, exponent: i32) -> Self {
+        Self::round(
+            coefficient < 0,
+            BigInt::from(coefficient.unsigned_abs()),
+            i64::from(exponent),
+            false,
+        )
+    }
+
+    fn special(class: Class, negative: bool) -> Self {
+        let () = Self::VALID_DIGITS;
+        Decimal {
+            class,
+            negative,
+            coefficient: 0,
+            exponent: 0,
+        }
+    }
+
+    fn zero_with_sign(negative: bool) -> Self {
+        Self::special(Class::Finite, negative)
+    }
+
+    fn infinity_with_sign(negative: bool) -> Self {
+        Self::special(Class::Infinite, negative)
+    }
+
+    fn not_a_number() -> Self {
+        Self::special(Class::Nan, false)
+    }
+
+    /// Round `±(coefficient + tail) * 10^exponent` to `DIGITS` digits, where the tail is
+    /// below one and non-zero if `inexact` is set
+    ///
+    /// `inexact` only breaks ties, so the coefficient must have more than `DIGITS` digits if
+    /// it is set.
+    fn round(negative: bool, coefficient: BigInt, mut exponent: i64, inexact: bool) -> Self {
+        let () = Self::VALID_DIGITS;
+        let excess = num_digits(&coefficient) - i64::from(DIGITS);
+        let mut coefficient = if excess > 0 {
+            let divisor = pow10(excess as u64);
+            let (quotient, remainder) = coefficient.checked_div_rem(&divisor).unwrap();
+            let quotient = quotient.to_u128().unwrap();
+            exponent += excess;
+            match (&remainder + &remainder).cmp(&divisor) {
+                Ordering::Greater => quotient + 1,
+                Ordering::Equal if inexact || quotient % 2 == 1 => quotient + 1,
+                _ => quotient,
+            }
+        } else {
+            coefficient.to_u128().unwrap()
+        };
+        if coefficient == 0 {
+            return Self::zero_with_sign(negative);
+        }
+        while coefficient % 10 == 0 {
+            coefficient /= 10;
+            exponent += 1;
+        }
+        let adjusted = exponent + i64::from(coefficient.ilog10());
+        if adjusted > MAX_EXPONENT {
+            Self::infinity_with_sign(negative)
+        } else if adjusted < MIN_EXPONENT {
+            Self::zero_with_sign(negative)
+        } else {
+            Decimal {
+                class: Class::Finite,
+                negative,
+                coefficient,
+                exponent: exponent as i32,
+            }
+        }
+    }
+
+    /// Round `±numerator / denominator * 10^exponent` for a positive `denominator`
+    fn round_quotient(
+        negative: bool,
+        numerator: &BigInt,
+        denominator: &BigInt,
+        exponent: i64,
+    ) -> Self {
+        // Keep two more digits than needed, so that the remainder only breaks ties
+        let shift =
+            (i64::from(DIGITS) + 2 + num_digits(denominator) - num_digits(numerator)).max(0);
+        let (quotient, remainder) = (numerator * &pow10(shift as u64))
+            .checked_div_rem(denominator)
+            .unwrap();
+        Self::round(negative, quotient, exponent - shift, !remainder.is_zero())
+    }
+
+    /// Round an intermediate result
+    fn round_extended(value: &Extended) -> Self {
+        Self::round(
+            value.is_negative(),
+            value.coefficient.abs(),
+            value.exponent,
+            false,
+        )
+    }
+
+    /// Exact value of a finite number
+    fn to_extended(self) -> Extended {
+        let coefficient = BigInt::from(self.coefficient);
+        Extended::new(
+            if self.negative {
+                -coefficient
+            } else {
+                coefficient
+            },
+            i64::from(self.exponent),
+        )
+    }
+
+    /// Correctly rounded sum of two exact values, where an exact zero sum is positive
+    fn round_sum(lhs: &Extended, rhs: &Extended) -> Self {
+        let (large, small) = if lhs.adjusted() >= rhs.adjusted() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        if !small.is_zero() {
+            // Scale the larger operand to more digits than kept. If the smaller one is below
+            // its last digit then, only its sign matters for rounding.
+            let shift = (i64::from(DIGITS) + 2 - num_digits(&large.coefficient)).max(0);
+            let exponent = large.exponent - shift;
+            if small.adjusted() < exponent {
+                let mut coefficient = &large.coefficient.abs() * &pow10(shift as u64);
+                if small.is_negative() != large.is_negative() {
+                    coefficient -= BigInt::one();
+                }
+                return Self::round(large.is_negative(), coefficient, exponent, true);
+            }
+        }
+        Self::round_extended(&large.add_exact(small))
+    }
+
+    /// Whether this is a finite number
+    fn is_finite_number(&self) -> bool {
+        self.class == Class::Finite
+    }
+
+    /// Number of digits of the coefficient
+    fn coefficient_digits(&self) -> i64 {
+        match self.coefficient {
+            0 => 0,
+            coefficient => i64::from(coefficient.ilog10()) + 1,
+        }
+    }
+
+    /// Order of the magnitudes of two non-NaN values
+    fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (self.class, other.class) {
+            (Class::Infinite, Class::Infinite) => return Ordering::Equal,
+            (Class::Infinite, _) => return Ordering::Greater,
+            (_, Class::Infinite) => return Ordering::Less,
+            _ => {}
+        }
+        match (self.coefficient, other.coefficient) {
+            (0, 0) => return Ordering::Equal,
+            (0, _) => return Ordering::Less,
+            (_, 0) => return Ordering::Greater,
+            _ => {}
+        }
+        let self_digits = self.coefficient_digits();
+        let other_digits = other.coefficient_digits();
+        (i64::from(self.exponent) + self_digits)
+            .cmp(&(i64::from(other.exponent) + other_digits))
+            .then_with(|| {
+                // Align both coefficients to `DIGITS` digits, which cannot overflow
+                let align = |coefficient: u128, digits: i64| {
+                    coefficient * 10u128.pow((i64::from(DIGITS) - digits) as u32)
+                };
+                align(self.coefficient, self_digits).cmp(&align(other.coefficient, other_digits))
+            })
+    }
+
+    /// Significant digits and exponent of the last one, `None` for special values
+    fn digits(&self) -> Option<(String, i64)> {
+        if self.is_finite_number() {
+            Some((self.coefficient.to_string(), i64::from(self.exponent)))
+        } else {
+            None
+        }
+    }
+
+    /// Round to `exponent`, i.e. to a multiple of `10^exponent`, halves to even, and return
+    /// the coefficient of that multiple
+    fn quantize(&self, exponent: i64) -> BigInt {
+        let value = self.to_extended().abs();
+        if value.exponent >= exponent {
+            return value.coefficient * pow10((value.exponent - exponent) as u64);
+        }
+        let divisor = pow10((exponent - value.exponent) as u64);
+        let (quotient, remainder) = value.coefficient.checked_div_rem(&divisor).unwrap();
+        let is_odd = !(&quotient % &BigInt::from(2)).is_zero();
+        match (&remainder + &remainder).cmp(&divisor) {
+            Ordering::Greater => quotient + BigInt::one(),
+            Ordering::Equal if is_odd => quotient + BigInt::one(),
+            _ => quotient,
+        }
+    }
+}
+
+/// Digits with a radix point before the last `fraction_digits` of them
+fn with_radix_point(digits: &str, fraction_digits: usize) -> String {
+    if fraction_digits == 0 {
+        return digits.to_string();
+    }
+    if digits.len() > fraction_digits {
+        let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+        format!("{}.{}", integer, fraction)
+    } else {
+        format!("0.{:0>width$}", digits, width = fraction_digits)
+    }
+}
+
+/// Scientific notation of significant digits with the exponent of the first digit
+fn scientific(digits: &str, exponent: i64, exponent_char: char) -> String {
+    let (first, rest) = digits.split_at(1);
+    if rest.is_empty() {
+        format!("{}{}{}", first, exponent_char, exponent)
+    } else {
+        format!("{}.{}{}{}", first, rest, exponent_char, exponent)
+    }
+}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    /// Format with the given number of fraction digits, rounded halves to even
+    fn fixed(&self, fraction_digits: usize) -> String {
+        let coefficient = self.quantize(-(fraction_digits as i64));
+        with_radix_point(
+            &format!("{:0>width$}", coefficient, width = fraction_digits + 1),
+            fraction_digits,
+        )
+    }
+
+    /// Format in scientific notation with the given number of fraction digits
+    fn exponential(&self, fraction_digits: Option<usize>, exponent_char: char) -> String {
+        let (digits, exponent) = match self.digits() {
+            Some((digits, exponent)) => (digits, exponent),
+            None => unreachable!("special values are formatted separately"),
+        };
+        let adjusted = exponent + digits.len() as i64 - 1;
+        match fraction_digits {
+            None => scientific(&digits, adjusted, exponent_char),
+            Some(fraction_digits) => {
+                let mut adjusted = adjusted;
+                let mut rounded = self.quantize(adjusted - fraction_digits as i64).to_string();
+                if rounded.len() > fraction_digits + 1 {
+                    // Rounded up to the next power of ten
+                    rounded.pop();
+                    adjusted += 1;
+                }
+                let rounded = format!("{:0<width$}", rounded, width = fraction_digits + 1);
+                scientific(&rounded, adjusted, exponent_char)
+            }
+        }
+    }
+
+    /// Write a special value or the formatted magnitude with sign and padding
+    fn pad(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        format_magnitude: impl FnOnce(&Self) -> String,
+    ) -> std::fmt::Result {
+        match self.class {
+            Class::Nan => f.pad("NaN"),
+            Class::Infinite => f.pad_integral(!self.negative, "", "inf"),
+            Class::Finite => f.pad_integral(!self.negative, "", &format_magnitude(self)),
+        }
+    }
+}
+
+impl<const DIGITS: u32> std::fmt::Display for Decimal<DIGITS> {
+    /// Print all significant digits, in scientific notation below 1e-7 and from 10^DIGITS
+    ///
+    /// With a precision, print that many fraction digits instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// let value: Decimal = "-1234.5".parse().unwrap();
+    ///
+    /// assert_eq!(value.to_string(), "-1234.5");
+    /// assert_eq!(format!("{:.2}", value), "-1234.50");
+    /// assert_eq!(format!("{:.0}", value), "-1234");
+    /// assert_eq!("1e34".parse::<Decimal>().unwrap().to_string(), "1e34");
+    /// assert_eq!("1e33".parse::<Decimal>().unwrap().to_string(), "1000000000000000000000000000000000");
+    /// assert_eq!("0.0000001".parse::<Decimal>().unwrap().to_string(), "0.0000001");
+    /// assert_eq!("0.00000001".parse::<Decimal>().unwrap().to_string(), "1e-8");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        self.pad(f, |value| {
+            let (digits, exponent) = value.digits().unwrap();
+            let adjusted = exponent + digits.len() as i64 - 1;
+            match precision {
+                Some(fraction_digits) => value.fixed(fraction_digits),
+                None if value.coefficient == 0 => "0".to_string(),
+                None if (-7..i64::from(DIGITS)).contains(&adjusted) => {
+                    if exponent >= 0 {
+                        format!("{}{}", digits, "0".repeat(exponent as usize))
+                    } else {
+                        with_radix_point(&digits, exponent.unsigned_abs() as usize)
+                    }
+                }
+                None => scientific(&digits, adjusted, 'e'),
+            }
+        })
+    }
+}
+
+impl<const DIGITS: u32> std::fmt::Debug for Decimal<DIGITS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const DIGITS: u32> std::fmt::LowerExp for Decimal<DIGITS> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// let value: Decimal = "1234.5".parse().unwrap();
+    ///
+    /// assert_eq!(format!("{:e}", value), "1.2345e3");
+    /// assert_eq!(format!("{:.2e}", value), "1.23e3");
+    /// assert_eq!(format!("{:.1e}", "9.96".parse::<Decimal>().unwrap()), "1.0e1");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        self.pad(f, |value| match value.coefficient {
+            0 => scientific(&"0".repeat(precision.unwrap_or(0) + 1), 0, 'e'),
+            _ => value.exponential(precision, 'e'),
+        })
+    }
+}
+
+impl<const DIGITS: u32> std::fmt::UpperExp for Decimal<DIGITS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        self.pad(f, |value| match value.coefficient {
+            0 => scientific(&"0".repeat(precision.unwrap_or(0) + 1), 0, 'E'),
+            _ => value.exponential(precision, 'E'),
+        })
+    }
+}
+
+impl<const DIGITS: u32> std::str::FromStr for Decimal<DIGITS> {
+    type Err = ParseDecimalError;
+
+    /// Parse a number like `-12.5e-3`, `inf`, `infinity` or `nan` and round it to `DIGITS` digits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::{Decimal, ParseDecimalError};
+    ///
+    /// assert_eq!("+.5E1".parse::<Decimal>().unwrap().to_string(), "5");
+    /// assert_eq!("-Infinity".parse::<Decimal>().unwrap().to_string(), "-inf");
+    /// assert_eq!("0.1234".parse::<Decimal<2>>().unwrap().to_string(), "0.12");
+    /// assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+    /// assert_eq!("1.2.3".parse::<Decimal>(), Err(ParseDecimalError::Invalid));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if unsigned.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        if unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity") {
+            return Ok(Self::infinity_with_sign(negative));
+        }
+        if unsigned.eq_ignore_ascii_case("nan") {
+            return Ok(Self::not_a_number());
+        }
+
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(idx) => (&unsigned[..idx], Some(&unsigned[idx + 1..])),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = match mantissa.find('.') {
+            Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+            None => (mantissa, ""),
+        };
+        let all_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+        if integer.len() + fraction.len() == 0 || !all_digits(integer) || !all_digits(fraction) {
+            return Err(ParseDecimalError::Invalid);
+        }
+        let exponent = match exponent {
+            Some(exponent) => parse_exponent(exponent).ok_or(ParseDecimalError::Invalid)?,
+            None => 0,
+        };
+
+        let digits = format!("{}{}", integer, fraction);
+        let coefficient: BigInt = digits.trim_start_matches('0').parse().unwrap_or_default();
+        Ok(Self::round(
+            negative,
+            coefficient,
+            exponent - fraction.len() as i64,
+            false,
+        ))
+    }
+}
+
+/// Parse an exponent with optional sign, saturated far beyond the range of decimals
+fn parse_exponent(s: &str) -> Option<i64> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    const LIMIT: i64 = 1 << 40;
+    let magnitude = digits.bytes().fold(0i64, |value, c| {
+        (value * 10 + i64::from(c - b'0')).min(LIMIT)
+    });
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+impl<const DIGITS: u32> Default for Decimal<DIGITS> {
+    fn default() -> Self {
+        Self::zero_with_sign(false)
+    }
+}
+
+impl<const DIGITS: u32> PartialEq for Decimal<DIGITS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<const DIGITS: u32> PartialOrd for Decimal<DIGITS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.class == Class::Nan || other.class == Class::Nan {
+            return None;
+        }
+        let self_negative = self.negative && !self.is_zero();
+        let other_negative = other.negative && !other.is_zero();
+        Some(match (self_negative, other_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.cmp_abs(other),
+            (true, true) => other.cmp_abs(self),
+        })
+    }
+}
+
+impl<const DIGITS: u32> Add for Decimal<DIGITS> {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// let big = Decimal::<34>::new(1, 40);
+    /// let small = Decimal::<34>::new(-1, 0);
+    ///
+    /// assert_eq!((big + small).to_string(), "1e40");
+    /// assert_eq!((big + small * Decimal::new(1, 10)).to_string(), "9.99999999999999999999999999999e39");
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        match (self.class, rhs.class) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::not_a_number(),
+            (Class::Infinite, Class::Infinite) if self.negative != rhs.negative => {
+                Self::not_a_number()
+            }
+            (Class::Infinite, _) => self,
+            (_, Class::Infinite) => rhs,
+            _ if self.coefficient == 0 && rhs.coefficient == 0 => {
+                Self::zero_with_sign(self.negative && rhs.negative)
+            }
+            _ if rhs.coefficient == 0 => self,
+            _ if self.coefficient == 0 => rhs,
+            _ => Self::round_sum(&self.to_extended(), &rhs.to_extended()),
+        }
+    }
+}
+
+impl<const DIGITS: u32> Sub for Decimal<DIGITS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const DIGITS: u32> Mul for Decimal<DIGITS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let negative = self.negative != rhs.negative;
+        let is_zero = |value: &Self| value.is_finite_number() && value.coefficient == 0;
+        match (self.class, rhs.class) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::not_a_number(),
+            (Class::Infinite, _) | (_, Class::Infinite) if is_zero(&self) || is_zero(&rhs) => {
+                Self::not_a_number()
+            }
+            (Class::Infinite, _) | (_, Class::Infinite) => Self::infinity_with_sign(negative),
+            _ => Self::round(
+                negative,
+                BigInt::from(self.coefficient) * BigInt::from(rhs.coefficient),
+                i64::from(self.exponent) + i64::from(rhs.exponent),
+                false,
+            ),
+        }
+    }
+}
+
+impl<const DIGITS: u32> Div for Decimal<DIGITS> {
+    type Output = Self;
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// let one = Decimal::<34>::new(1, 0);
+    ///
+    /// assert_eq!((one / Decimal::new(8, 0)).to_string(), "0.125");
+    /// assert_eq!((one / Decimal::new(-3, 0)).to_string(), "-0.3333333333333333333333333333333333");
+    /// assert_eq!((one / Decimal::new(0, 0)).to_string(), "inf");
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        let negative = self.negative != rhs.negative;
+        match (self.class, rhs.class) {
+            (Class::Nan, _) | (_, Class::Nan) => Self::not_a_number(),
+            (Class::Infinite, Class::Infinite) => Self::not_a_number(),
+            (Class::Infinite, _) => Self::infinity_with_sign(negative),
+            (_, Class::Infinite) => Self::zero_with_sign(negative),
+            _ if rhs.coefficient == 0 && self.coefficient == 0 => Self::not_a_number(),
+            _ if rhs.coefficient == 0 => Self::infinity_with_sign(negative),
+            _ if self.coefficient == 0 => Self::zero_with_sign(negative),
+            _ => Self::round_quotient(
+                negative,
+                &BigInt::from(self.coefficient),
+                &BigInt::from(rhs.coefficient),
+                i64::from(self.exponent) - i64::from(rhs.exponent),
+            ),
+        }
+    }
+}
+
+impl<const DIGITS: u32> Rem for Decimal<DIGITS> {
+    type Output = Self;
+
+    /// Remainder of the division truncated towards zero, which is exact
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Decimal;
+    ///
+    /// let value: Decimal = "-7.5".parse().unwrap();
+    ///
+    /// assert_eq!((value % Decimal::new(2, 0)).to_string(), "-1.5");
+    /// assert_eq!((Decimal::<34>::new(1, 100) % Decimal::new(3, -1)).to_string(), "0.1");
+    /// ```
+    fn rem(self, rhs: Self) -> Self {
+        match (self.class, rhs.class) {
+            (Class::Finite, Class::Finite) if rhs.coefficient != 0 => {
+                let exponent = self.exponent.min(rhs.exponent);
+                let align = |value: &Self| {
+                    BigInt::from(value.coefficient)
+                        * pow10(i64::from(value.exponent - exponent) as u64)
+                };
+                let remainder = align(&self) % align(&rhs);
+                if remainder.is_zero() {
+                    Self::zero_with_sign(self.negative)
+                } else {
+                    Self::round(self.negative, remainder, i64::from(exponent), false)
+                }
+            }
+            (Class::Finite, Class::Infinite) => self,
+            _ => Self::not_a_number(),
+        }
+    }
+}
+
+impl<const DIGITS: u32> Neg for Decimal<DIGITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.class {
+            Class::Nan => self,
+            _ => Decimal {
+                negative: !self.negative,
+                ..self
+            },
+        }
+    }
+}
+
+/// Implement the operators for borrowed operands and the assignment variants
+/// on top of the implementation for owned operands
+macro_rules! decimal_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<'a, const DIGITS: u32> $trait<&'a Decimal<DIGITS>> for Decimal<DIGITS> {
+            type Output = Decimal<DIGITS>;
+
+            fn $method(self, rhs: &'a Decimal<DIGITS>) -> Decimal<DIGITS> {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<const DIGITS: u32> $assign_trait for Decimal<DIGITS> {
+            fn $assign_method(&mut self, rhs: Decimal<DIGITS>) {
+                *self = self.$method(rhs);
+            }
+        }
+
+        impl<'a, const DIGITS: u32> $assign_trait<&'a Decimal<DIGITS>> for Decimal<DIGITS> {
+            fn $assign_method(&mut self, rhs: &'a Decimal<DIGITS>) {
+                *self = self.$method(*rhs);
+            }
+        }
+    };
+}
+
+decimal_operator!(Add, add, AddAssign, add_assign);
+decimal_operator!(Sub, sub, SubAssign, sub_assign);
+decimal_operator!(Mul, mul, MulAssign, mul_assign);
+decimal_operator!(Div, div, DivAssign, div_assign);
+decimal_operator!(Rem, rem, RemAssign, rem_assign);
+
+impl<const DIGITS: u32> Zero for Decimal<DIGITS> {
+    fn zero() -> Self {
+        Self::zero_with_sign(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_finite_number() && self.coefficient == 0
+    }
+}
+
+impl<const DIGITS: u32> One for Decimal<DIGITS> {
+    fn one() -> Self {
+        Self::new(1, 0)
+    }
+}
+
+impl<const DIGITS: u32> num_traits::Num for Decimal<DIGITS> {
+    type FromStrRadixErr = ParseDecimalError;
+
+    /// Parse a number in a radix from 2 to 36, with an exponent only in radix 10
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is outside of 2 to 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Num;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::from_str_radix("-ff.8", 16).unwrap().to_string(), "-255.5");
+    /// assert_eq!(Decimal::<34>::from_str_radix("0.1", 3).unwrap().to_string(), "0.3333333333333333333333333333333333");
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseDecimalError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in 2..=36, not {}",
+            radix
+        );
+        if radix == 10 {
+            return s.parse();
+        }
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(idx) => (&unsigned[..idx], &unsigned[idx + 1..]),
+            None => (unsigned, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(if unsigned.is_empty() {
+                ParseDecimalError::Empty
+            } else {
+                ParseDecimalError::Invalid
+            });
+        }
+        let digits = format!("{}{}", integer, fraction);
+        if !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(ParseDecimalError::Invalid);
+        }
+        let numerator = BigInt::from_str_radix(&digits, radix).unwrap();
+        let denominator = BigInt::from(radix).pow(fraction.len() as u32);
+        Ok(if numerator.is_zero() {
+            Self::zero_with_sign(negative)
+        } else {
+            Self::round_quotient(negative, &numerator, &denominator, 0)
+        })
+    }
+}
+
+impl<const DIGITS: u32> Signed for Decimal<DIGITS> {
+    fn abs(&self) -> Self {
+        num_traits::Float::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        num_traits::Float::abs_sub(*self, *other)
+    }
+
+    fn signum(&self) -> Self {
+        num_traits::Float::signum(*self)
+    }
+
+    fn is_positive(&self) -> bool {
+        num_traits::Float::is_sign_positive(*self)
+    }
+
+    fn is_negative(&self) -> bool {
+        num_traits::Float::is_sign_negative(*self)
+    }
+}
+
+impl<const DIGITS: u32> num_traits::FromPrimitive for Decimal<DIGITS> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::new(i128::from(n), 0))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::new(i128::from(n), 0))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::new(n, 0))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::round(false, BigInt::from(n), 0, false))
+    }
+
+    /// Convert the shortest decimal which converts back to the same `f64`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::FromPrimitive;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!(Decimal::<34>::from_f64(0.1).unwrap().to_string(), "0.1");
+    /// assert_eq!(Decimal::<34>::from_f64(-1e300).unwrap().to_string(), "-1e300");
+    /// ```
+    fn from_f64(n: f64) -> Option<Self> {
+        format!("{:e}", n).parse().ok()
+    }
+}
+
+impl<const DIGITS: u32> ToPrimitive for Decimal<DIGITS> {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|value| value.to_i64())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|value| value.to_u64())
+    }
+
+    /// Integer part, [None] if it does not fit
+    fn to_i128(&self) -> Option<i128> {
+        self.integer_part()?.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.integer_part()?.to_u128()
+    }
+
+    /// Nearest `f64`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::ToPrimitive;
+    /// use smsflib::number::Decimal;
+    ///
+    /// assert_eq!("0.1".parse::<Decimal>().unwrap().to_f64(), Some(0.1));
+    /// assert_eq!("-1e400".parse::<Decimal>().unwrap().to_f64(), Some(f64::NEG_INFINITY));
+    /// ```
+    fn to_f64(&self) -> Option<f64> {
+        format!("{:e}", self).parse().ok()
+    }
+}
+
+impl<const DIGITS: u32> Decimal<DIGITS> {
+    /// Value truncated towards zero, [None] for special values
+    fn integer_part(&self) -> Option<BigInt> {
+        if !self.is_finite_number() {
+            return None;
+        }
+        let value = self.to_extended();
+        Some(if value.exponent >= 0 {
+            value.coefficient * pow10(value.exponent as u64)
+        } else if value.exponent < -38 {
+            BigInt::zero()
+        } else {
+            value.coefficient / pow10(value.exponent.unsigned_abs())
+        })
+    }
+}
+
+impl<const DIGITS: u32> num_traits::NumCast for Decimal<DIGITS> {
+    /// Convert integers exactly and floating-point numbers via
+    /// [from_f64](num_traits::FromPrimitive::from_f64)
+    ///
+    /// Integral values from 2^53 on are taken as integers, so that integers of any size
+    /// are converted exactly.
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        use num_traits::FromPrimitive;
+        let approximation = n.to_f64()?;
+        if approximation.fract() != 0.0 || approximation.abs() < 9_007_199_254_740_992.0 {
+            return Self::from_f64(approximation);
+        }
+        match (n.to_i128(), n.to_u128()) {
+            (Some(value), _) => Self::from_i128(value),
+            (None, Some(value)) => Self::from_u128(value),
+            (None, None) => Self::from_f64(approximation),
+        }
+    }
+}
+
+/// # Note
+/// Decimals saturate to infinity instead of overflowing, so all policies return the rounded
+/// result.
+impl<const DIGITS: u32> OverflowArithmetic for Decimal<DIGITS> {
+    fn add_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(*self + *rhs)
+    }
+
+    fn sub_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(*self - *rhs)
+    }
+
+    fn mul_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(*self * *rhs)
+    }
+
+    fn div_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(*self / *rhs)
+    }
+
+    fn neg_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(-*self)
+    }
+
+    fn abs_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        Some(num_traits::Float::abs(*self))
+    }
+}
@@ -0,0 +1,54 @@
+use super::Rational;
+use crate::stack::InPlaceFnApplication;
+use num_traits::{Float, ToPrimitive, Zero};
+
+/// Conversion of floating-point values into fractions (→Q).
+pub trait FractionOperations: InPlaceFnApplication
+where
+    <Self as InPlaceFnApplication>::Elem: Float + std::fmt::Debug,
+{
+    /// Best fraction approximating Y with a denominator of at most X (→Q).
+    /// Leave the numerator in Y and the denominator in X.
+    ///
+    /// See [Rational::approximate](crate::number::Rational::approximate) for the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1000.0, std::f64::consts::PI, 2.0, 3.0);
+    /// stack.to_fraction();
+    ///
+    /// assert_eq!(*stack.x(), 113.0);
+    /// assert_eq!(*stack.y(), 355.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    ///
+    /// The maximum denominator must be a positive integer:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[0.5, 2.5]);
+    /// let res = stack.to_fraction();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("to_fraction", &[2.5, 0.5])));
+    /// ```
+    fn to_fraction(&mut self) -> Result<(), crate::StackError> {
+        self.try_nary_fn_in_place(|[x, y]: [<Self as InPlaceFnApplication>::Elem; 2]| {
+            let fraction = x
+                .to_i64()
+                .filter(|_| x.fract().is_zero())
+                .and_then(|max_denominator| Rational::approximate(y, max_denominator))
+                .ok_or_else(|| crate::StackError::domain_error("to_fraction", &[x, y]))?;
+            let numerator = num_traits::cast(fraction.numerator());
+            let denominator = num_traits::cast(fraction.denominator());
+            match (denominator, numerator) {
+                (Some(denominator), Some(numerator)) => Ok([denominator, numerator]),
+                _ => Err(crate::StackError::domain_error("to_fraction", &[x, y])),
+            }
+        })
+    }
+}
@@ -0,0 +1,21 @@
+/// Units of trigonometric operations
+mod angle_mode;
+/// Arbitrary-precision integers
+mod big_int;
+/// Decimal floating-point numbers
+mod decimal;
+/// Best fraction approximations of floating-point values
+mod fraction;
+/// Integer overflow handling
+mod overflow_arithmetic;
+/// Exact fractions
+mod rational;
+/// Scalar kernels of the special functions, generic over `Float` with `f64` constants
+pub(crate) mod special_functions;
+
+pub use angle_mode::AngleMode;
+pub use big_int::{BigInt, ParseBigIntError};
+pub use decimal::{Decimal, ParseDecimalError};
+pub use fraction::FractionOperations;
+pub use overflow_arithmetic::{ArithmeticPolicy, OverflowArithmetic};
+pub use rational::{ParseRationalError, Rational};
@@ -0,0 +1,137 @@
+/// How integer arithmetic handles results which do not fit into the element type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArithmeticPolicy {
+    /// Fail with [Overflow](crate::StackError::Overflow), leaving the stack unchanged
+    #[default]
+    Checked,
+    /// Wrap around at the boundary of the type
+    Wrapping,
+    /// Clamp to the minimum or maximum value of the type
+    Saturating,
+}
+
+/// Arithmetic following an [ArithmeticPolicy].
+///
+/// All functions return [None] if the result overflows under the [Checked](ArithmeticPolicy::Checked)
+/// policy. Types which cannot overflow, such as floating-point numbers, ignore the policy.
+/// Division by zero is not handled here and must be ruled out by the caller.
+///
+/// # Example
+///
+/// ```
+/// use smsflib::number::{ArithmeticPolicy, OverflowArithmetic};
+///
+/// assert_eq!(100_i8.add_with(&100, ArithmeticPolicy::Checked), None);
+/// assert_eq!(100_i8.add_with(&100, ArithmeticPolicy::Wrapping), Some(-56));
+/// assert_eq!(100_i8.add_with(&100, ArithmeticPolicy::Saturating), Some(127));
+///
+/// assert_eq!(1e308_f64.add_with(&1e308, ArithmeticPolicy::Checked), Some(f64::INFINITY));
+/// ```
+pub trait OverflowArithmetic: Sized {
+    fn add_with(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self>;
+
+    fn sub_with(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self>;
+
+    fn mul_with(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self>;
+
+    fn div_with(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self>;
+
+    fn neg_with(&self, policy: ArithmeticPolicy) -> Option<Self>;
+
+    fn abs_with(&self, policy: ArithmeticPolicy) -> Option<Self>;
+}
+
+macro_rules! integer_binary_with {
+    ($name:ident, $checked:ident, $wrapping:ident, $saturating:ident) => {
+        fn $name(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self> {
+            match policy {
+                ArithmeticPolicy::Checked => self.$checked(*rhs),
+                ArithmeticPolicy::Wrapping => Some(self.$wrapping(*rhs)),
+                ArithmeticPolicy::Saturating => Some(self.$saturating(*rhs)),
+            }
+        }
+    };
+}
+
+macro_rules! integer_overflow_arithmetic {
+    ($signedness:ident: $($t:ty)*) => {$(
+        impl OverflowArithmetic for $t {
+            integer_binary_with!(add_with, checked_add, wrapping_add, saturating_add);
+            integer_binary_with!(sub_with, checked_sub, wrapping_sub, saturating_sub);
+            integer_binary_with!(mul_with, checked_mul, wrapping_mul, saturating_mul);
+
+            fn div_with(&self, rhs: &Self, policy: ArithmeticPolicy) -> Option<Self> {
+                // Only MIN / -1 overflows, saturating it yields MAX
+                match policy {
+                    ArithmeticPolicy::Checked => self.checked_div(*rhs),
+                    ArithmeticPolicy::Wrapping => Some(self.wrapping_div(*rhs)),
+                    ArithmeticPolicy::Saturating => {
+                        Some(self.checked_div(*rhs).unwrap_or(<$t>::MAX))
+                    }
+                }
+            }
+
+            fn neg_with(&self, policy: ArithmeticPolicy) -> Option<Self> {
+                match policy {
+                    ArithmeticPolicy::Checked => self.checked_neg(),
+                    ArithmeticPolicy::Wrapping => Some(self.wrapping_neg()),
+                    ArithmeticPolicy::Saturating => Some((0 as $t).saturating_sub(*self)),
+                }
+            }
+
+            integer_abs_with!($signedness);
+        }
+    )*};
+}
+
+macro_rules! integer_abs_with {
+    (signed) => {
+        fn abs_with(&self, policy: ArithmeticPolicy) -> Option<Self> {
+            match policy {
+                ArithmeticPolicy::Checked => self.checked_abs(),
+                ArithmeticPolicy::Wrapping => Some(self.wrapping_abs()),
+                ArithmeticPolicy::Saturating => Some(self.saturating_abs()),
+            }
+        }
+    };
+    (unsigned) => {
+        fn abs_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+            Some(*self)
+        }
+    };
+}
+
+integer_overflow_arithmetic!(signed: i8 i16 i32 i64 i128 isize);
+integer_overflow_arithmetic!(unsigned: u8 u16 u32 u64 u128 usize);
+
+macro_rules! float_overflow_arithmetic {
+    ($($t:ty)*) => {$(
+        impl OverflowArithmetic for $t {
+            fn add_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            fn sub_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(self - rhs)
+            }
+
+            fn mul_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(self * rhs)
+            }
+
+            fn div_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(self / rhs)
+            }
+
+            fn neg_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(-self)
+            }
+
+            fn abs_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+                Some(self.abs())
+            }
+        }
+    )*};
+}
+
+float_overflow_arithmetic!(f32 f64);
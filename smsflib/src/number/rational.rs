@@ -0,0 +1,659 @@
+use super::{ArithmeticPolicy, OverflowArithmetic};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Float, One, Zero};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Exact fraction of two `i64` values, always in lowest terms with a positive denominator.
+///
+/// Stacks of rationals support the [BasicMathOperations](crate::stack::BasicMathOperations),
+/// [SignedMathOperations](crate::stack::SignedMathOperations) and
+/// [PercentageOperations](crate::stack::PercentageOperations).
+/// The operators panic on overflow and division by zero like the primitive integers,
+/// the stack operations report them as errors instead.
+///
+/// # Example
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = DynamicSizedStack::<Rational>::new();
+/// stack.push(Rational::new(1, 3));
+/// stack.push(Rational::new(1, 6));
+/// stack.add();
+///
+/// assert_eq!(stack.get(0), Some(&Rational::new(1, 2)));
+/// assert_eq!(stack.get(0).unwrap().to_string(), "1/2");
+///
+/// stack.push(Rational::from_integer(0));
+/// let res = stack.divide();
+///
+/// assert_eq!(res, Err(smsflib::StackError::division_by_zero("divide", &[Rational::from_integer(0), Rational::new(1, 2)])));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+/// Reason why a string could not be parsed into a [Rational]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRationalError {
+    /// The string is not an integer, a fraction `n/d` or a number with a radix point
+    Invalid,
+    /// The denominator is zero
+    ZeroDenominator,
+    /// The value does not fit into a [Rational]
+    Overflow,
+}
+
+impl std::fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRationalError::Invalid => write!(f, "invalid rational number"),
+            ParseRationalError::ZeroDenominator => write!(f, "zero denominator"),
+            ParseRationalError::Overflow => write!(f, "rational number too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+/// Greatest common divisor of two non-negative values
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+impl Rational {
+    /// Create a fraction and reduce it to lowest terms
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the reduced fraction does not fit, e.g. `1/i64::MIN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// let q = Rational::new(6, -4);
+    ///
+    /// assert_eq!(q.numerator(), -3);
+    /// assert_eq!(q.denominator(), 2);
+    /// ```
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "denominator of a rational number is zero");
+        Self::checked_new(numerator, denominator).expect("rational number overflow")
+    }
+
+    /// Create a fraction and reduce it to lowest terms,
+    /// [None] if `denominator` is zero or the reduced fraction does not fit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::checked_new(2, 4), Some(Rational::new(1, 2)));
+    /// assert_eq!(Rational::checked_new(1, 0), None);
+    /// assert_eq!(Rational::checked_new(1, i64::MIN), None);
+    /// ```
+    pub fn checked_new(numerator: i64, denominator: i64) -> Option<Self> {
+        Self::reduced(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// Create a fraction with denominator one
+    pub fn from_integer(value: i64) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    /// Get the numerator, which carries the sign
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// Get the denominator, which is always positive
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Check if the denominator is one
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Convert into the nearest value of a floating-point type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::new(3, 8).to_float::<f64>(), 0.375);
+    /// ```
+    pub fn to_float<T: Float>(&self) -> T {
+        T::from(self.numerator).unwrap() / T::from(self.denominator).unwrap()
+    }
+
+    /// Remainder of the truncated division, [None] on division by zero or overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Zero;
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::new(7, 2).checked_rem(&Rational::new(4, 3)), Some(Rational::new(5, 6)));
+    /// assert_eq!(Rational::new(-7, 2).checked_rem(&Rational::new(4, 3)), Some(Rational::new(-5, 6)));
+    /// assert_eq!(Rational::new(1, 2).checked_rem(&Rational::zero()), None);
+    /// ```
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        let (a, b) = self.wide();
+        let (c, d) = rhs.wide();
+        if c == 0 {
+            return None;
+        }
+        Self::reduced((a * d) % (c * b), b * d)
+    }
+
+    /// Best rational approximation of `value` with a denominator of at most `max_denominator`,
+    /// found by its continued fraction expansion.
+    ///
+    /// Of all fractions with a denominator up to `max_denominator`, the result is the closest
+    /// to `value`, preferring the smaller denominator on ties. Values below 2<sup>-11</sup>
+    /// in magnitude are rounded to a multiple of 2<sup>-64</sup> first, which only matters
+    /// for maximum denominators beyond 2<sup>32</sup>.
+    /// Returns [None] if `value` is not finite, `max_denominator` is not positive or the result
+    /// does not fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// let pi = std::f64::consts::PI;
+    ///
+    /// assert_eq!(Rational::approximate(pi, 10), Some(Rational::new(22, 7)));
+    /// assert_eq!(Rational::approximate(pi, 1000), Some(Rational::new(355, 113)));
+    /// assert_eq!(Rational::approximate(-0.1, 1_000_000), Some(Rational::new(-1, 10)));
+    /// assert_eq!(Rational::approximate(0.75, 1), Some(Rational::from_integer(1)));
+    /// assert_eq!(Rational::approximate(f64::NAN, 10), None);
+    /// assert_eq!(Rational::approximate(1e19, 10), None);
+    /// ```
+    pub fn approximate<T: Float>(value: T, max_denominator: i64) -> Option<Self> {
+        const MAX_SCALE: i16 = 64;
+
+        if !value.is_finite() || max_denominator < 1 {
+            return None;
+        }
+        let (mantissa, exponent, sign) = value.integer_decode();
+        let mantissa = u128::from(mantissa);
+        // value = sign * numerator / denominator with denominator = 2^scale
+        let (numerator, scale) = if exponent >= 64 {
+            return None;
+        } else if exponent >= 0 {
+            (mantissa << exponent, 0)
+        } else if -exponent <= MAX_SCALE {
+            (mantissa, -exponent)
+        } else {
+            let shift = u32::from((-exponent - MAX_SCALE).unsigned_abs());
+            let rounded = mantissa
+                .checked_shr(shift - 1)
+                .map_or(0, |halves| (halves + 1) >> 1);
+            (rounded, MAX_SCALE)
+        };
+        if numerator >> scale > i64::MAX as u128 {
+            return None;
+        }
+        let (numerator, denominator) = best_approximation(
+            numerator,
+            1u128 << scale,
+            max_denominator.unsigned_abs().into(),
+        );
+        let numerator = i64::try_from(numerator).ok()?;
+        Some(Rational {
+            numerator: if sign < 0 { -numerator } else { numerator },
+            denominator: i64::try_from(denominator).ok()?,
+        })
+    }
+
+    /// Reduce `numerator / denominator` to lowest terms, [None] if it does not fit
+    fn reduced(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator.abs(), denominator.abs()) * denominator.signum();
+        Some(Rational {
+            numerator: i64::try_from(numerator / divisor).ok()?,
+            denominator: i64::try_from(denominator / divisor).ok()?,
+        })
+    }
+
+    /// Numerator and denominator widened for intermediate results
+    fn wide(&self) -> (i128, i128) {
+        (i128::from(self.numerator), i128::from(self.denominator))
+    }
+}
+
+/// Best approximation of `numerator / denominator` with a denominator of at most
+/// `max_denominator`, returned as numerator and denominator.
+///
+/// `numerator` must be below 2<sup>127</sup>, `denominator` at most 2<sup>64</sup>
+/// and `max_denominator` positive.
+fn best_approximation(
+    mut numerator: u128,
+    mut denominator: u128,
+    max_denominator: u128,
+) -> (u128, u128) {
+    // Previous and last convergent p0/q0 and p1/q1
+    let (mut p0, mut q0, mut p1, mut q1) = (0, 1, 1, 0);
+    while denominator != 0 {
+        let quotient = numerator / denominator;
+        let q2 = match quotient.checked_mul(q1).and_then(|q| q.checked_add(q0)) {
+            Some(q2) if q2 <= max_denominator => q2,
+            _ => break,
+        };
+        let p2 = p0 + quotient * p1;
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+        let remainder = numerator - quotient * denominator;
+        numerator = denominator;
+        denominator = remainder;
+    }
+    if denominator == 0 {
+        return (p1, q1);
+    }
+    // The semiconvergent with the largest allowed denominator lies on the other side of the
+    // value. It is closer than the last convergent iff x' < q0 / q1 + 2j, where x' is the
+    // complete quotient numerator / denominator.
+    let j = (max_denominator - q0) / q1;
+    let semiconvergent = (p0 + j * p1, q0 + j * q1);
+    match (2 * j)
+        .checked_mul(denominator)
+        .and_then(|twice_j| numerator.checked_sub(twice_j))
+    {
+        None => semiconvergent,
+        Some(rest) if rest < denominator && rest * q1 < q0 * denominator => semiconvergent,
+        Some(_) => (p1, q1),
+    }
+}
+
+impl std::fmt::Display for Rational {
+    /// Print `n/d`, or only `n` for integers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::new(-2, 6).to_string(), "-1/3");
+    /// assert_eq!(Rational::new(4, 2).to_string(), "2");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl std::fmt::Debug for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::str::FromStr for Rational {
+    type Err = ParseRationalError;
+
+    /// Parse an integer, a fraction `n/d` or a decimal number
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::{ParseRationalError, Rational};
+    ///
+    /// assert_eq!("-3/12".parse(), Ok(Rational::new(-1, 4)));
+    /// assert_eq!("1.25".parse(), Ok(Rational::new(5, 4)));
+    /// assert_eq!("7".parse(), Ok(Rational::from_integer(7)));
+    /// assert_eq!("1/0".parse::<Rational>(), Err(ParseRationalError::ZeroDenominator));
+    /// assert_eq!("1/2/3".parse::<Rational>(), Err(ParseRationalError::Invalid));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Rational as num_traits::Num>::from_str_radix(s, 10)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::number::Rational;
+    ///
+    /// assert!(Rational::new(1, 3) < Rational::new(1, 2));
+    /// assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = self.wide();
+        let (c, d) = other.wide();
+        (a * d).cmp(&(c * b))
+    }
+}
+
+impl CheckedAdd for Rational {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let (a, b) = self.wide();
+        let (c, d) = rhs.wide();
+        Self::reduced(a * d + c * b, b * d)
+    }
+}
+
+impl CheckedSub for Rational {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let (a, b) = self.wide();
+        let (c, d) = rhs.wide();
+        Self::reduced(a * d - c * b, b * d)
+    }
+}
+
+impl CheckedMul for Rational {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (a, b) = self.wide();
+        let (c, d) = rhs.wide();
+        Self::reduced(a * c, b * d)
+    }
+}
+
+impl CheckedDiv for Rational {
+    /// [None] on division by zero or overflow
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        let (a, b) = self.wide();
+        let (c, d) = rhs.wide();
+        Self::reduced(a * d, b * c)
+    }
+}
+
+impl CheckedNeg for Rational {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+/// Implement an operator and its assignment variant for owned and borrowed operands
+/// on top of a checked operation, panicking with `message` if it fails
+macro_rules! rational_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $checked:ident, $message:expr) => {
+        impl $trait for Rational {
+            type Output = Rational;
+
+            fn $method(self, rhs: Rational) -> Rational {
+                self.$checked(&rhs).expect($message)
+            }
+        }
+
+        impl<'a> $trait<&'a Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, rhs: &'a Rational) -> Rational {
+                self.$checked(rhs).expect($message)
+            }
+        }
+
+        impl $assign_trait for Rational {
+            fn $assign_method(&mut self, rhs: Rational) {
+                *self = self.$checked(&rhs).expect($message);
+            }
+        }
+
+        impl<'a> $assign_trait<&'a Rational> for Rational {
+            fn $assign_method(&mut self, rhs: &'a Rational) {
+                *self = self.$checked(rhs).expect($message);
+            }
+        }
+    };
+}
+
+rational_operator!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    checked_add,
+    "rational addition overflow"
+);
+rational_operator!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    checked_sub,
+    "rational subtraction overflow"
+);
+rational_operator!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    checked_mul,
+    "rational multiplication overflow"
+);
+rational_operator!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    checked_div,
+    "rational division by zero or overflow"
+);
+rational_operator!(
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    checked_rem,
+    "rational remainder by zero or overflow"
+);
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("rational negation overflow")
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+}
+
+impl num_traits::Num for Rational {
+    type FromStrRadixErr = ParseRationalError;
+
+    /// Parse an integer, a fraction `n/d` or a number with a radix point in the given radix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::Num;
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::from_str_radix("ff/100", 16), Ok(Rational::new(255, 256)));
+    /// assert_eq!(Rational::from_str_radix("-0.1", 2), Ok(Rational::new(-1, 2)));
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        let parse_integer = |digits: &str| -> Result<i128, ParseRationalError> {
+            if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+                return Err(ParseRationalError::Invalid);
+            }
+            i128::from_str_radix(digits, radix).map_err(|_| ParseRationalError::Overflow)
+        };
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (numerator, denominator) =
+            if let Some((numerator, denominator)) = unsigned.split_once('/') {
+                (parse_integer(numerator)?, parse_integer(denominator)?)
+            } else if let Some((integer, fraction)) = unsigned.split_once('.') {
+                let scale = i128::from(radix)
+                    .checked_pow(
+                        u32::try_from(fraction.len()).map_err(|_| ParseRationalError::Overflow)?,
+                    )
+                    .ok_or(ParseRationalError::Overflow)?;
+                let integer = if integer.is_empty() {
+                    0
+                } else {
+                    parse_integer(integer)?
+                };
+                let fraction = if fraction.is_empty() {
+                    0
+                } else {
+                    parse_integer(fraction)?
+                };
+                let numerator = integer
+                    .checked_mul(scale)
+                    .and_then(|n| n.checked_add(fraction))
+                    .ok_or(ParseRationalError::Overflow)?;
+                (numerator, scale)
+            } else {
+                (parse_integer(unsigned)?, 1)
+            };
+        if denominator == 0 {
+            return Err(ParseRationalError::ZeroDenominator);
+        }
+        let numerator = if negative { -numerator } else { numerator };
+        Rational::reduced(numerator, denominator).ok_or(ParseRationalError::Overflow)
+    }
+}
+
+impl num_traits::Signed for Rational {
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Rational::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Rational::from_integer(self.numerator.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+}
+
+impl num_traits::FromPrimitive for Rational {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Rational::from_integer(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        i64::try_from(n).ok().map(Rational::from_integer)
+    }
+
+    /// Convert exactly, [None] if the value has no exact representation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_traits::FromPrimitive;
+    /// use smsflib::number::Rational;
+    ///
+    /// assert_eq!(Rational::from_f64(-0.375), Some(Rational::new(-3, 8)));
+    /// assert_eq!(Rational::from_f64(0.1), Some(Rational::new(3_602_879_701_896_397, 36_028_797_018_963_968)));
+    /// assert_eq!(Rational::from_f64(1e-30), None);
+    /// ```
+    fn from_f64(n: f64) -> Option<Self> {
+        let (mantissa, exponent, sign) = n.integer_decode();
+        if !n.is_finite() {
+            return None;
+        }
+        let numerator = i128::from(mantissa) * i128::from(sign);
+        match exponent {
+            0..=63 => Rational::reduced(numerator << exponent, 1),
+            -126..=-1 => Rational::reduced(numerator, 1 << -exponent),
+            _ => None,
+        }
+    }
+}
+
+/// # Note
+/// Rational results are exact or do not exist, so all policies fail on overflow
+/// and return [None].
+impl OverflowArithmetic for Rational {
+    fn add_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    fn sub_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+
+    fn mul_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    fn div_with(&self, rhs: &Self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    fn neg_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        self.checked_neg()
+    }
+
+    fn abs_with(&self, _policy: ArithmeticPolicy) -> Option<Self> {
+        if self.numerator < 0 {
+            self.checked_neg()
+        } else {
+            Some(*self)
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use num_traits::Float;
+use std::f64::consts::PI;
+
+/// Convert an `f64` constant into `T`
+fn constant<T: Float>(value: f64) -> T {
+    T::from(value).unwrap()
+}
+
+/// Parameter `g` of the Lanczos approximation
+const LANCZOS_G: f64 = 7.0;
+
+/// Coefficients of the Lanczos approximation for `g = 7` and nine terms
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Largest integer whose gamma function is finite in `f64`, products of integers up to it
+/// are used instead of the Lanczos approximation
+const GAMMA_PRODUCT_LIMIT: f64 = 171.0;
+
+/// Euler–Mascheroni constant
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Bessel functions of arguments at least this large use the asymptotic expansion
+const BESSEL_ASYMPTOTIC_THRESHOLD: f64 = 25.0;
+
+/// Bessel functions of positive arguments below this use the leading terms of their series,
+/// whose relative error is then below `x² |ln x|`, since Miller's recurrence overflows for tiny x
+const BESSEL_SERIES_THRESHOLD: f64 = 1e-9;
+
+/// `sin(πx)`, exact for integer `x`
+fn sin_pi<T: Float>(x: T) -> T {
+    let one = T::one();
+    let two = constant::<T>(2.0);
+    let half = constant::<T>(0.5);
+    // Reduce into [-0.5, 0.5] without rounding, so that zeros stay zeros
+    let mut r = x % two;
+    if r > one {
+        r = r - two;
+    } else if r < -one {
+        r = r + two;
+    }
+    if r > half {
+        r = one - r;
+    } else if r < -half {
+        r = -one - r;
+    }
+    (constant::<T>(PI) * r).sin()
+}
+
+/// Series part of the Lanczos approximation of `Γ(z + 1)`
+fn lanczos_sum<T: Float>(z: T) -> T {
+    LANCZOS_COEFFICIENTS[1..].iter().enumerate().fold(
+        constant(LANCZOS_COEFFICIENTS[0]),
+        |sum, (idx, &coefficient)| {
+            sum + constant::<T>(coefficient) / (z + constant((idx + 1) as f64))
+        },
+    )
+}
+
+/// `Γ(n) = (n - 1)!` of a positive integer `n` up to [GAMMA_PRODUCT_LIMIT] as a product,
+/// which is exact as long as the factorial fits into the mantissa
+fn gamma_of_integer<T: Float>(n: T) -> Option<T> {
+    if n.fract().is_zero() && n >= T::one() && n <= constant(GAMMA_PRODUCT_LIMIT) {
+        let n = n.to_usize()?;
+        Some((2..n).fold(T::one(), |product, k| product * constant(k as f64)))
+    } else {
+        None
+    }
+}
+
+/// Gamma function, NaN at its poles
+pub(crate) fn gamma<T: Float>(x: T) -> T {
+    if let Some(product) = gamma_of_integer(x) {
+        return product;
+    }
+    let half = constant::<T>(0.5);
+    if x < half {
+        if x == x.floor() {
+            return T::nan();
+        }
+        // Reflection formula
+        return constant::<T>(PI) / (sin_pi(x) * gamma(T::one() - x));
+    }
+    let z = x - T::one();
+    let t = z + constant(LANCZOS_G + 0.5);
+    let decay = (-t).exp();
+    if x == T::infinity() || decay.is_zero() {
+        return T::infinity();
+    }
+    // Split the power so that it does not overflow before the decay is applied
+    let power = t.powf(half * (z + half));
+    constant::<T>((2.0 * PI).sqrt()) * power * (power * decay) * lanczos_sum(z)
+}
+
+/// Natural logarithm of the absolute value of the gamma function, infinite at its poles
+pub(crate) fn ln_gamma<T: Float>(x: T) -> T {
+    if let Some(product) = gamma_of_integer(x) {
+        return product.ln();
+    }
+    let half = constant::<T>(0.5);
+    if x < half {
+        if x == x.floor() {
+            return T::infinity();
+        }
+        // Reflection formula
+        return (constant::<T>(PI) / sin_pi(x).abs()).ln() - ln_gamma(T::one() - x);
+    }
+    if x == T::infinity() {
+        return x;
+    }
+    let z = x - T::one();
+    let t = z + constant(LANCZOS_G + 0.5);
+    constant::<T>((2.0 * PI).sqrt().ln()) + (z + half) * t.ln() - t + lanczos_sum(z).ln()
+}
+
+/// Beta function
+pub(crate) fn beta<T: Float>(x: T, y: T) -> T {
+    if x > T::zero() && y > T::zero() {
+        (ln_gamma(x) + ln_gamma(y) - ln_gamma(x + y)).exp()
+    } else {
+        gamma(x) * gamma(y) / gamma(x + y)
+    }
+}
+
+/// `exp(-x²)` without the rounding error of `x²` for large `x`
+fn exp_minus_square<T: Float>(x: T) -> T {
+    let sixteen = constant::<T>(16.0);
+    // The square of the truncated value is exact
+    let truncated = (x * sixteen).floor() / sixteen;
+    let coarse = (-truncated * truncated).exp();
+    if coarse.is_zero() {
+        return coarse;
+    }
+    coarse * (-(x - truncated) * (x + truncated)).exp()
+}
+
+/// Error function of a non-negative `x` by a series with positive terms only
+fn erf_series<T: Float>(x: T) -> T {
+    let two = constant::<T>(2.0);
+    let x2 = two * x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1;
+    while term > T::epsilon() * sum {
+        term = term * x2 / constant((2 * n + 1) as f64);
+        sum = sum + term;
+        n += 1;
+    }
+    constant::<T>(2.0 / PI.sqrt()) * exp_minus_square(x) * sum
+}
+
+/// Complementary error function of `x >= 0.5` by a continued fraction
+fn erfc_continued_fraction<T: Float>(x: T) -> T {
+    const MAX_ITERATIONS: usize = 10_000;
+
+    let scale = exp_minus_square(x);
+    if scale.is_zero() {
+        return scale;
+    }
+    // Modified Lentz's method for x + (1/2)/(x + 1/(x + (3/2)/(x + ...)))
+    let mut fraction = x;
+    let mut c = x;
+    let mut d = T::zero();
+    for n in 1..=MAX_ITERATIONS {
+        let a = constant::<T>(n as f64 / 2.0);
+        d = (x + a * d).recip();
+        c = x + a / c;
+        let delta = c * d;
+        fraction = fraction * delta;
+        if (delta - T::one()).abs() <= T::epsilon() {
+            break;
+        }
+    }
+    scale / (constant::<T>(PI.sqrt()) * fraction)
+}
+
+/// Error function
+pub(crate) fn erf<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        x
+    } else if x < T::zero() {
+        -erf(-x)
+    } else if x < constant(2.0) {
+        erf_series(x)
+    } else {
+        T::one() - erfc_continued_fraction(x)
+    }
+}
+
+/// Complementary error function
+pub(crate) fn erfc<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        x
+    } else if x < T::zero() {
+        constant::<T>(2.0) - erfc(-x)
+    } else if x < constant(0.5) {
+        T::one() - erf_series(x)
+    } else {
+        erfc_continued_fraction(x)
+    }
+}
+
+/// Initial guess for the inverse error function of `0 < y < 1`, accurate to single precision
+/// (M. Giles, "Approximating the erfinv function", 2010)
+fn erf_inv_guess<T: Float>(y: T) -> T {
+    const CENTRAL: [f64; 9] = [
+        2.810_226_36e-08,
+        3.432_739_39e-07,
+        -3.523_387_7e-06,
+        -4.391_506_54e-06,
+        0.000_218_580_87,
+        -0.001_253_725_03,
+        -0.004_177_681_64,
+        0.246_640_727,
+        1.501_409_41,
+    ];
+    const TAIL: [f64; 9] = [
+        -0.000_200_214_257,
+        0.000_100_950_558,
+        0.001_349_343_22,
+        -0.003_673_428_44,
+        0.005_739_507_73,
+        -0.007_622_461_3,
+        0.009_438_870_47,
+        1.001_674_06,
+        2.832_976_82,
+    ];
+    let w = -((T::one() - y) * (T::one() + y)).ln();
+    let (w, coefficients) = if w < constant(5.0) {
+        (w - constant(2.5), &CENTRAL)
+    } else {
+        (w.sqrt() - constant(3.0), &TAIL)
+    };
+    let p = coefficients
+        .iter()
+        .fold(T::zero(), |p, &coefficient| p * w + constant(coefficient));
+    p * y
+}
+
+/// Inverse error function, NaN outside of [-1, 1]
+pub(crate) fn erf_inv<T: Float>(y: T) -> T {
+    const MAX_ITERATIONS: usize = 20;
+
+    let abs_y = y.abs();
+    if abs_y.is_nan() || abs_y > T::one() {
+        return T::nan();
+    } else if abs_y == T::one() {
+        return copysign(T::infinity(), y);
+    } else if abs_y.is_zero() {
+        return y;
+    }
+    let half = constant::<T>(0.5);
+    // Exact by Sterbenz' lemma for |y| >= 0.5, where it is used
+    let complement = T::one() - abs_y;
+    let mut x = erf_inv_guess(abs_y);
+    // Refine by Halley's method, solving erfc(x) = 1 - |y| in the tail to keep the precision
+    for _ in 0..MAX_ITERATIONS {
+        let derivative = constant::<T>(2.0 / PI.sqrt()) * exp_minus_square(x);
+        let step = if abs_y <= half {
+            let residual = erf(x) - abs_y;
+            residual / (derivative + x * residual)
+        } else {
+            let residual = erfc(x) - complement;
+            residual / (x * residual - derivative)
+        };
+        x = x - step;
+        if step.abs() <= T::epsilon() * x.abs() {
+            break;
+        }
+    }
+    copysign(x, y)
+}
+
+/// `magnitude` with the sign of `sign`
+fn copysign<T: Float>(magnitude: T, sign: T) -> T {
+    if magnitude.is_sign_negative() == sign.is_sign_negative() {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
+
+/// Bessel functions `J_0(x)` to `J_m(x)` of a positive `x` by Miller's backward recurrence,
+/// normalised by `J_0 + 2 J_2 + 2 J_4 + … = 1`
+fn bessel_j_sequence<T: Float>(x: T) -> Vec<T> {
+    let rescale_threshold = constant::<T>(1e10);
+    let x_f64 = x.to_f64().unwrap();
+    let m = 2 * ((x_f64 + 15.0 + (40.0 * x_f64).sqrt()) / 2.0) as usize;
+    let mut js = vec![T::zero(); m + 2];
+    js[m] = T::one();
+    for k in (1..=m).rev() {
+        js[k - 1] = constant::<T>(2.0 * k as f64) / x * js[k] - js[k + 1];
+        if js[k - 1].abs() > rescale_threshold {
+            for j in &mut js[k - 1..] {
+                *j = *j / rescale_threshold;
+            }
+        }
+    }
+    let norm = js[2..=m]
+        .iter()
+        .step_by(2)
+        .fold(js[0], |norm, &j| norm + constant::<T>(2.0) * j);
+    js.iter().map(|&j| j / norm).collect()
+}
+
+/// Bessel functions `(J_n(x), Y_n(x))` of order zero or one and a large `x`
+/// by Hankel's asymptotic expansion
+fn bessel_asymptotic<T: Float>(order: u8, x: T) -> (T, T) {
+    let mu = constant::<T>(4.0 * f64::from(order * order));
+    let eight_x = constant::<T>(8.0) * x;
+    let mut p = T::zero();
+    let mut q = T::zero();
+    let mut term = T::one();
+    let mut k: usize = 0;
+    // Sum until the terms are negligible or start to grow
+    loop {
+        let sign = if (k / 2).is_multiple_of(2) {
+            T::one()
+        } else {
+            -T::one()
+        };
+        if k.is_multiple_of(2) {
+            p = p + sign * term;
+        } else {
+            q = q + sign * term;
+        }
+        k += 1;
+        let odd = constant::<T>((2 * k - 1) as f64);
+        let next_term = term * (mu - odd * odd) / (constant::<T>(k as f64) * eight_x);
+        if next_term.abs() >= term.abs() || next_term.abs() < T::epsilon() * constant(1e-3) {
+            break;
+        }
+        term = next_term;
+    }
+    // χ = x - (n/2 + 1/4)π, expanded to keep the precision of sin(x) and cos(x)
+    let (sin_x, cos_x) = x.sin_cos();
+    let frac_1_sqrt_2 = constant::<T>(std::f64::consts::FRAC_1_SQRT_2);
+    let (cos_chi, sin_chi) = if order == 0 {
+        (
+            (cos_x + sin_x) * frac_1_sqrt_2,
+            (sin_x - cos_x) * frac_1_sqrt_2,
+        )
+    } else {
+        (
+            (sin_x - cos_x) * frac_1_sqrt_2,
+            -(sin_x + cos_x) * frac_1_sqrt_2,
+        )
+    };
+    let amplitude = (constant::<T>(2.0 / PI) / x).sqrt();
+    (
+        amplitude * (p * cos_chi - q * sin_chi),
+        amplitude * (p * sin_chi + q * cos_chi),
+    )
+}
+
+/// Bessel function of the first kind of order zero
+pub(crate) fn bessel_j0<T: Float>(x: T) -> T {
+    let x = x.abs();
+    if x.is_nan() {
+        x
+    } else if x.is_infinite() {
+        T::zero()
+    } else if x < constant(BESSEL_SERIES_THRESHOLD) {
+        // J_0 ≈ 1 - x²/4
+        T::one() - x * x / constant(4.0)
+    } else if x >= constant(BESSEL_ASYMPTOTIC_THRESHOLD) {
+        bessel_asymptotic(0, x).0
+    } else {
+        bessel_j_sequence(x)[0]
+    }
+}
+
+/// Bessel function of the first kind of order one
+pub(crate) fn bessel_j1<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        x
+    } else if x.is_infinite() {
+        T::zero()
+    } else if x < T::zero() {
+        -bessel_j1(-x)
+    } else if x < constant(BESSEL_SERIES_THRESHOLD) {
+        // J_1 ≈ x/2
+        x / constant(2.0)
+    } else if x >= constant(BESSEL_ASYMPTOTIC_THRESHOLD) {
+        bessel_asymptotic(1, x).0
+    } else {
+        bessel_j_sequence(x)[1]
+    }
+}
+
+/// Bessel function of the second kind of order zero, NaN for negative `x`
+pub(crate) fn bessel_y0<T: Float>(x: T) -> T {
+    if x.is_nan() || x < T::zero() {
+        T::nan()
+    } else if x.is_zero() {
+        T::neg_infinity()
+    } else if x.is_infinite() {
+        T::zero()
+    } else if x < constant(BESSEL_SERIES_THRESHOLD) {
+        // Y_0 ≈ 2/π (ln(x/2) + γ)
+        constant::<T>(2.0 / PI) * ((x / constant(2.0)).ln() + constant(EULER_GAMMA))
+    } else if x >= constant(BESSEL_ASYMPTOTIC_THRESHOLD) {
+        bessel_asymptotic(0, x).1
+    } else {
+        // Neumann series Y_0 = 2/π (ln(x/2) + γ) J_0 - 4/π Σ (-1)^k J_2k / k
+        let js = bessel_j_sequence(x);
+        let series = (1..(js.len() - 1) / 2).fold(T::zero(), |sum, k| {
+            let term = js[2 * k] / constant(k as f64);
+            if k.is_multiple_of(2) {
+                sum + term
+            } else {
+                sum - term
+            }
+        });
+        let log_term = (x / constant(2.0)).ln() + constant(EULER_GAMMA);
+        constant::<T>(2.0 / PI) * log_term * js[0] - constant::<T>(4.0 / PI) * series
+    }
+}
+
+/// Bessel function of the second kind of order one, NaN for negative `x`
+pub(crate) fn bessel_y1<T: Float>(x: T) -> T {
+    if x.is_nan() || x < T::zero() {
+        T::nan()
+    } else if x.is_zero() {
+        T::neg_infinity()
+    } else if x.is_infinite() {
+        T::zero()
+    } else if x < constant(BESSEL_SERIES_THRESHOLD) {
+        // Y_1 ≈ -2/(πx)
+        -constant::<T>(2.0 / PI) / x
+    } else if x >= constant(BESSEL_ASYMPTOTIC_THRESHOLD) {
+        bessel_asymptotic(1, x).1
+    } else {
+        // Derivative of the Neumann series of Y_0, using J_2k' = (J_2k-1 - J_2k+1) / 2
+        let js = bessel_j_sequence(x);
+        let series = (1..(js.len() - 2) / 2).fold(T::zero(), |sum, k| {
+            let term = (js[2 * k - 1] - js[2 * k + 1]) / constant(k as f64);
+            if k.is_multiple_of(2) {
+                sum + term
+            } else {
+                sum - term
+            }
+        });
+        let log_term = (x / constant(2.0)).ln() + constant(EULER_GAMMA);
+        let frac_2_pi = constant::<T>(2.0 / PI);
+        frac_2_pi * (log_term * js[1] + series - js[0] / x)
+    }
+}
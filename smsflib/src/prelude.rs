@@ -1,6 +1,14 @@
 // Traits
+pub use crate::number::FractionOperations;
 pub use crate::stack::{
-    BasicMathOperations, BasicStackOperations, FloatMathOperations, InPlaceFnApplication,
+    BasicMathOperations, BasicStackOperations, CalculatorState, DepthOperations,
+    ExtendedStackOperations, FloatMathOperations, InPlaceFnApplication, IntegerMathOperations,
+    LastXRegister, PercentageOperations, SignedMathOperations, SpecialFunctions, Stack,
+    Transaction,
 };
 // Types
-pub use crate::stack::{ClassicStack, DynamicSizedStack};
+pub use crate::number::{AngleMode, ArithmeticPolicy, BigInt, Decimal, Rational};
+pub use crate::stack::{
+    ClassicStack, DeepStackPolicy, DynamicSizedStack, FixedStack, ShortStackFill,
+    StackOverflowPolicy, UndoStack,
+};
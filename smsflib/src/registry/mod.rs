@@ -0,0 +1,7 @@
+/// Operation descriptors
+mod operation;
+/// Name-based lookup of operations
+mod operation_registry;
+
+pub use operation::{Arity, Operation};
+pub use operation_registry::OperationRegistry;
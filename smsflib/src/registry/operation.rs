@@ -0,0 +1,127 @@
+/// Number of stack levels an operation consumes and produces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Arity {
+    /// Number of operands taken from the stack
+    pub num_inputs: usize,
+    /// Number of results left on the stack
+    pub num_outputs: usize,
+}
+
+impl Arity {
+    /// Create a new arity descriptor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::registry::Arity;
+    ///
+    /// let arity = Arity::new(2, 1);
+    ///
+    /// assert_eq!(arity.num_inputs, 2);
+    /// assert_eq!(arity.num_outputs, 1);
+    /// ```
+    pub const fn new(num_inputs: usize, num_outputs: usize) -> Self {
+        Arity {
+            num_inputs,
+            num_outputs,
+        }
+    }
+}
+
+/// A named operation applicable to a stack of type `S`
+pub struct Operation<S> {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    arity: Arity,
+    description: &'static str,
+    function: fn(&mut S) -> Result<(), crate::StackError>,
+}
+
+impl<S> Operation<S> {
+    /// Create a new operation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, Operation};
+    ///
+    /// let op = Operation::<DynamicSizedStack<i32>>::new(
+    ///     "double",
+    ///     &["2*"],
+    ///     Arity::new(1, 1),
+    ///     "Multiply X by two",
+    ///     |stack| stack.unary_fn_in_place(|x: &mut i32| *x *= 2),
+    /// );
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[21]);
+    /// let res = op.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&42));
+    /// ```
+    pub fn new(
+        name: &'static str,
+        aliases: &'static [&'static str],
+        arity: Arity,
+        description: &'static str,
+        function: fn(&mut S) -> Result<(), crate::StackError>,
+    ) -> Self {
+        Operation {
+            name,
+            aliases,
+            arity,
+            description,
+            function,
+        }
+    }
+
+    /// Get the canonical name
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get alternative names, e.g. symbols like `+` or `x<>y`
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Get the number of consumed and produced stack levels
+    pub fn arity(&self) -> Arity {
+        self.arity
+    }
+
+    /// Get a one-line description
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Check if the operation is known under the given name or one of its aliases
+    pub fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+
+    /// Apply the operation to a stack
+    pub fn apply(&self, stack: &mut S) -> Result<(), crate::StackError> {
+        (self.function)(stack)
+    }
+}
+
+impl<S> Clone for Operation<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Operation<S> {}
+
+impl<S> std::fmt::Debug for Operation<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Operation")
+            .field("name", &self.name)
+            .field("aliases", &self.aliases)
+            .field("arity", &self.arity)
+            .field("description", &self.description)
+            .finish()
+    }
+}
@@ -0,0 +1,1073 @@
+use super::{Arity, Operation};
+use crate::number::{FractionOperations, OverflowArithmetic};
+use crate::stack::{
+    BasicMathOperations, BasicStackOperations, ExtendedStackOperations, FloatMathOperations,
+    InPlaceFnApplication, IntegerMathOperations, LastXRegister, PercentageOperations,
+    SignedMathOperations, SpecialFunctions,
+};
+use std::collections::HashMap;
+
+/// Collection of operations for a stack of type `S`, searchable by canonical name or alias
+pub struct OperationRegistry<S> {
+    operations: Vec<Operation<S>>,
+    index: HashMap<&'static str, usize>,
+}
+
+impl<S> OperationRegistry<S> {
+    /// Create a new empty registry
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::OperationRegistry;
+    ///
+    /// let registry = OperationRegistry::<DynamicSizedStack<f64>>::new();
+    ///
+    /// assert_eq!(registry.len(), 0);
+    /// assert!(registry.get("+").is_none());
+    /// ```
+    pub fn new() -> Self {
+        OperationRegistry {
+            operations: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Add an operation.
+    ///
+    /// An operation registered earlier under the same canonical name is replaced.
+    /// Aliases always resolve to the most recently registered operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, Operation, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<i32>>::new();
+    /// registry.register(Operation::new(
+    ///     "square",
+    ///     &["x^2"],
+    ///     Arity::new(1, 1),
+    ///     "Square X",
+    ///     |stack| stack.unary_fn_in_place(|x: &mut i32| *x *= *x),
+    /// ));
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[7]);
+    /// let res = registry.get("x^2").unwrap().apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&49));
+    /// ```
+    pub fn register(&mut self, operation: Operation<S>) -> &mut Self {
+        let position = match self.index.get(operation.name()) {
+            Some(&position) if self.operations[position].name() == operation.name() => {
+                self.operations[position] = operation;
+                position
+            }
+            _ => {
+                self.operations.push(operation);
+                self.operations.len() - 1
+            }
+        };
+        self.index.insert(operation.name(), position);
+        for alias in operation.aliases() {
+            self.index.insert(alias, position);
+        }
+        self
+    }
+
+    /// Find an operation by its canonical name or one of its aliases
+    pub fn get(&self, name: &str) -> Option<&Operation<S>> {
+        self.index
+            .get(name)
+            .map(|&position| &self.operations[position])
+            .filter(|operation| operation.matches(name))
+    }
+
+    /// Apply the operation with the given canonical name or alias to the stack
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::OperationRegistry;
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<f64>>::new();
+    /// registry.register_basic_math_operations();
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[1.0, 2.0]);
+    ///
+    /// assert_eq!(registry.apply("+", &mut stack), Ok(()));
+    /// assert_eq!(stack.get(0), Some(&3.0));
+    ///
+    /// let res = registry.apply("frobnicate", &mut stack);
+    /// assert_eq!(res, Err(smsflib::StackError::UnknownOperation { name: "frobnicate".to_string() }));
+    /// ```
+    pub fn apply(&self, name: &str, stack: &mut S) -> Result<(), crate::StackError> {
+        match self.get(name) {
+            Some(operation) => operation.apply(stack),
+            None => Err(crate::StackError::UnknownOperation {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    /// Iterate over all operations in registration order
+    pub fn iter(&self) -> std::slice::Iter<'_, Operation<S>> {
+        self.operations.iter()
+    }
+
+    /// Iterate over all names and aliases which resolve to an operation, e.g. for completion
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.operations.iter().flat_map(move |operation| {
+            std::iter::once(operation.name())
+                .chain(operation.aliases().iter().copied())
+                .filter(move |name| self.get(name).map(Operation::name) == Some(operation.name()))
+        })
+    }
+
+    /// Get the number of registered operations
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Check if no operations are registered
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+impl<S> Default for OperationRegistry<S> {
+    fn default() -> Self {
+        OperationRegistry::new()
+    }
+}
+
+impl<S> std::fmt::Debug for OperationRegistry<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.operations.iter()).finish()
+    }
+}
+
+impl<S: BasicStackOperations> OperationRegistry<S> {
+    /// Register the operations of the [BasicStackOperations] trait.
+    ///
+    /// `push` and `pop` are not included, since they transfer values and are not
+    /// invoked by name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<ClassicStack<u32>>::new();
+    /// registry.register_basic_stack_operations();
+    ///
+    /// let swap = registry.get("x<>y").unwrap();
+    /// assert_eq!(swap.name(), "swap");
+    /// assert_eq!(swap.arity(), Arity::new(2, 2));
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = swap.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 2);
+    /// assert_eq!(*stack.y(), 1);
+    /// ```
+    pub fn register_basic_stack_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "rotate_up",
+            &["rup", "r^"],
+            Arity::new(0, 0),
+            "Rotate the stack up, moving the uppermost level to X",
+            S::rotate_up,
+        ))
+        .register(Operation::new(
+            "rotate_down",
+            &["rdown", "rv"],
+            Arity::new(0, 0),
+            "Rotate the stack down, moving X to the uppermost level",
+            S::rotate_down,
+        ))
+        .register(Operation::new(
+            "swap",
+            &["x<>y"],
+            Arity::new(2, 2),
+            "Swap X and Y",
+            S::swap,
+        ))
+        .register(Operation::new(
+            "drop",
+            &[],
+            Arity::new(1, 0),
+            "Remove X, shifting the other levels down",
+            S::drop,
+        ))
+        .register(Operation::new(
+            "clear",
+            &["clst"],
+            Arity::new(0, 0),
+            "Clear the whole stack",
+            S::clear,
+        ))
+    }
+}
+
+impl<S: ExtendedStackOperations> OperationRegistry<S> {
+    /// Register the operations of the [ExtendedStackOperations] trait with a fixed arity.
+    ///
+    /// Level-based operations such as `pick` take an argument and are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<u32>>::new();
+    /// registry.register_extended_stack_operations();
+    ///
+    /// let over = registry.get("over").unwrap();
+    /// assert_eq!(over.arity(), Arity::new(2, 3));
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = over.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// ```
+    pub fn register_extended_stack_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "dup",
+            &["enter"],
+            Arity::new(1, 2),
+            "Duplicate X",
+            S::dup,
+        ))
+        .register(Operation::new(
+            "dup2",
+            &[],
+            Arity::new(2, 4),
+            "Duplicate X and Y",
+            S::dup2,
+        ))
+        .register(Operation::new(
+            "over",
+            &[],
+            Arity::new(2, 3),
+            "Push a copy of Y",
+            S::over,
+        ))
+        .register(Operation::new(
+            "nip",
+            &[],
+            Arity::new(2, 1),
+            "Drop Y, keeping X",
+            S::nip,
+        ))
+        .register(Operation::new(
+            "tuck",
+            &[],
+            Arity::new(2, 3),
+            "Insert a copy of X below Y",
+            S::tuck,
+        ))
+        .register(Operation::new(
+            "drop2",
+            &[],
+            Arity::new(2, 0),
+            "Drop X and Y",
+            S::drop2,
+        ))
+    }
+}
+
+impl<S: LastXRegister> OperationRegistry<S> {
+    /// Register the operations of the [LastXRegister] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<i32>>::new();
+    /// registry.register_basic_math_operations().register_last_x_operations();
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[3, 4]);
+    /// let res = registry.get("+").unwrap().apply(&mut stack);
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// let last_x = registry.get("lastx").unwrap();
+    /// assert_eq!(last_x.arity(), Arity::new(0, 1));
+    ///
+    /// let res = last_x.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&4));
+    /// assert_eq!(stack.get(1), Some(&7));
+    /// ```
+    pub fn register_last_x_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "last_x",
+            &["lastx"],
+            Arity::new(0, 1),
+            "Recall the X consumed by the last operation",
+            S::last_x,
+        ))
+    }
+}
+
+impl<S: BasicMathOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem:
+        Clone + num_traits::NumAssignRef + crate::number::OverflowArithmetic + std::fmt::Debug,
+{
+    /// Register the operations of the [BasicMathOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<i32>>::new();
+    /// registry.register_basic_math_operations();
+    ///
+    /// let add = registry.get("+").unwrap();
+    /// assert_eq!(add.name(), "add");
+    /// assert_eq!(add.arity(), Arity::new(2, 1));
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[3, 4]);
+    /// let res = add.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.len(), 1);
+    /// assert_eq!(stack.get(0), Some(&7));
+    /// ```
+    ///
+    /// Like division, `-` takes Y as its first operand:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::OperationRegistry;
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<u32>>::new();
+    /// registry.register_basic_math_operations();
+    ///
+    /// // X = 3, Y = 10
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[10, 3]);
+    /// let res = registry.apply("-", &mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&7));
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 10]);
+    /// let res = registry.apply("-", &mut stack);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[10, 3])));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    pub fn register_basic_math_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "add",
+            &["+"],
+            Arity::new(2, 1),
+            "Add X to Y",
+            S::add,
+        ))
+        .register(Operation::new(
+            "subtract",
+            &["-", "sub"],
+            Arity::new(2, 1),
+            "Subtract X from Y",
+            // Y - X like divide, whereas BasicMathOperations::subtract computes X - Y
+            |stack: &mut S| {
+                let policy = stack.arithmetic_policy();
+                stack.try_binary_fn_in_place_second_arg(
+                    |x: &<S as InPlaceFnApplication>::Elem,
+                     y: &mut <S as InPlaceFnApplication>::Elem| {
+                        *y = y
+                            .sub_with(x, policy)
+                            .ok_or_else(|| crate::StackError::overflow("subtract", &[x, &*y]))?;
+                        Ok(())
+                    },
+                )
+            },
+        ))
+        .register(Operation::new(
+            "multiply",
+            &["*", "mul"],
+            Arity::new(2, 1),
+            "Multiply Y by X",
+            S::multiply,
+        ))
+        .register(Operation::new(
+            "divide",
+            &["/", "div"],
+            Arity::new(2, 1),
+            "Divide Y by X",
+            S::divide,
+        ))
+    }
+}
+
+impl<S: SignedMathOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::Signed
+        + crate::number::OverflowArithmetic
+        + std::fmt::Debug,
+{
+    /// Register the operations of the [SignedMathOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<i32>>::new();
+    /// registry.register_signed_math_operations();
+    ///
+    /// let chs = registry.get("chs").unwrap();
+    /// assert_eq!(chs.name(), "change_sign");
+    /// assert_eq!(chs.arity(), Arity::new(1, 1));
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[3, 4]);
+    /// let res = chs.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&-4));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// ```
+    pub fn register_signed_math_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "change_sign",
+            &["chs", "neg", "+/-"],
+            Arity::new(1, 1),
+            "Negate X",
+            S::change_sign,
+        ))
+        .register(Operation::new(
+            "absolute_value",
+            &["abs"],
+            Arity::new(1, 1),
+            "Absolute value of X",
+            S::absolute_value,
+        ))
+    }
+}
+
+impl<S: PercentageOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::FromPrimitive
+        + crate::number::OverflowArithmetic
+        + PartialOrd
+        + std::fmt::Debug,
+{
+    /// Register the operations of the [PercentageOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<ClassicStack<f64>>::new();
+    /// registry.register_percentage_operations();
+    ///
+    /// let percent_change = registry.get("d%").unwrap();
+    /// assert_eq!(percent_change.name(), "percent_change");
+    /// assert_eq!(percent_change.arity(), Arity::new(2, 2));
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(110.0, 100.0, 0.0, 0.0);
+    /// let res = percent_change.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 10.0);
+    /// assert_eq!(*stack.y(), 100.0);
+    /// ```
+    pub fn register_percentage_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "percent",
+            &["%"],
+            Arity::new(2, 2),
+            "X percent of Y, keeping Y",
+            S::percent,
+        ))
+        .register(Operation::new(
+            "percent_change",
+            &["d%", "delta%"],
+            Arity::new(2, 2),
+            "Change from Y to X in percent of Y, keeping Y",
+            S::percent_change,
+        ))
+        .register(Operation::new(
+            "percent_of_total",
+            &["%t"],
+            Arity::new(2, 2),
+            "X in percent of the total Y, keeping Y",
+            S::percent_of_total,
+        ))
+    }
+}
+
+impl<S: FloatMathOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: num_traits::Float + std::fmt::Debug,
+{
+    /// Register the operations of the [FloatMathOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<f64>>::new();
+    /// registry.register_float_math_operations();
+    ///
+    /// let pow = registry.get("y^x").unwrap();
+    /// assert_eq!(pow.name(), "pow");
+    /// assert_eq!(pow.arity(), Arity::new(2, 1));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[2.0, 10.0]);
+    /// let res = pow.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&1024.0));
+    /// ```
+    pub fn register_float_math_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "pow",
+            &["^", "y^x"],
+            Arity::new(2, 1),
+            "Raise Y to the power of X",
+            S::pow,
+        ))
+        .register(Operation::new(
+            "ln",
+            &[],
+            Arity::new(1, 1),
+            "Natural logarithm of X",
+            S::ln,
+        ))
+        .register(Operation::new(
+            "log2",
+            &[],
+            Arity::new(1, 1),
+            "Binary logarithm of X",
+            S::log2,
+        ))
+        .register(Operation::new(
+            "log10",
+            &["log"],
+            Arity::new(1, 1),
+            "Decimal logarithm of X",
+            S::log10,
+        ))
+        .register(Operation::new(
+            "exp",
+            &["e^x"],
+            Arity::new(1, 1),
+            "Raise e to the power of X",
+            S::exp,
+        ))
+        .register(Operation::new(
+            "exp2",
+            &["2^x"],
+            Arity::new(1, 1),
+            "Raise 2 to the power of X",
+            S::exp2,
+        ))
+        .register(Operation::new(
+            "sin",
+            &[],
+            Arity::new(1, 1),
+            "Sine of X",
+            S::sin,
+        ))
+        .register(Operation::new(
+            "cos",
+            &[],
+            Arity::new(1, 1),
+            "Cosine of X",
+            S::cos,
+        ))
+        .register(Operation::new(
+            "tan",
+            &[],
+            Arity::new(1, 1),
+            "Tangent of X",
+            S::tan,
+        ))
+        .register(Operation::new(
+            "asin",
+            &[],
+            Arity::new(1, 1),
+            "Arcsine of X",
+            S::asin,
+        ))
+        .register(Operation::new(
+            "acos",
+            &[],
+            Arity::new(1, 1),
+            "Arccosine of X",
+            S::acos,
+        ))
+        .register(Operation::new(
+            "atan",
+            &[],
+            Arity::new(1, 1),
+            "Arctangent of X",
+            S::atan,
+        ))
+        .register(Operation::new(
+            "atan2",
+            &[],
+            Arity::new(2, 1),
+            "Four-quadrant arctangent of Y/X",
+            S::atan2,
+        ))
+        .register(Operation::new(
+            "deg_to_rad",
+            &["d->r"],
+            Arity::new(1, 1),
+            "Convert X from degrees to radians",
+            S::deg_to_rad,
+        ))
+        .register(Operation::new(
+            "rad_to_deg",
+            &["r->d"],
+            Arity::new(1, 1),
+            "Convert X from radians to degrees",
+            S::rad_to_deg,
+        ))
+        .register(Operation::new(
+            "grad_to_rad",
+            &["g->r"],
+            Arity::new(1, 1),
+            "Convert X from gradians to radians",
+            S::grad_to_rad,
+        ))
+        .register(Operation::new(
+            "rad_to_grad",
+            &["r->g"],
+            Arity::new(1, 1),
+            "Convert X from radians to gradians",
+            S::rad_to_grad,
+        ))
+        .register(Operation::new(
+            "deg_to_grad",
+            &["d->g"],
+            Arity::new(1, 1),
+            "Convert X from degrees to gradians",
+            S::deg_to_grad,
+        ))
+        .register(Operation::new(
+            "grad_to_deg",
+            &["g->d"],
+            Arity::new(1, 1),
+            "Convert X from gradians to degrees",
+            S::grad_to_deg,
+        ))
+        .register(Operation::new(
+            "sqrt",
+            &[],
+            Arity::new(1, 1),
+            "Square root of X",
+            S::sqrt,
+        ))
+        .register(Operation::new(
+            "square",
+            &["sq", "x^2"],
+            Arity::new(1, 1),
+            "Square of X",
+            S::square,
+        ))
+        .register(Operation::new(
+            "reciprocal",
+            &["inv", "1/x"],
+            Arity::new(1, 1),
+            "Reciprocal of X",
+            S::reciprocal,
+        ))
+        .register(Operation::new(
+            "cbrt",
+            &[],
+            Arity::new(1, 1),
+            "Cube root of X",
+            S::cbrt,
+        ))
+        .register(Operation::new(
+            "nth_root",
+            &["xroot"],
+            Arity::new(2, 1),
+            "X-th root of Y",
+            S::nth_root,
+        ))
+        .register(Operation::new(
+            "hypot",
+            &[],
+            Arity::new(2, 1),
+            "Euclidean norm of X and Y",
+            S::hypot,
+        ))
+        .register(Operation::new(
+            "floor",
+            &[],
+            Arity::new(1, 1),
+            "Round X towards negative infinity",
+            S::floor,
+        ))
+        .register(Operation::new(
+            "ceil",
+            &[],
+            Arity::new(1, 1),
+            "Round X towards positive infinity",
+            S::ceil,
+        ))
+        .register(Operation::new(
+            "round",
+            &[],
+            Arity::new(1, 1),
+            "Round X to the nearest integer, halfway cases away from zero",
+            S::round,
+        ))
+        .register(Operation::new(
+            "trunc",
+            &["ip"],
+            Arity::new(1, 1),
+            "Integer part of X",
+            S::trunc,
+        ))
+        .register(Operation::new(
+            "fract",
+            &["fp"],
+            Arity::new(1, 1),
+            "Fractional part of X",
+            S::fract,
+        ))
+        .register(Operation::new(
+            "sign",
+            &["sgn"],
+            Arity::new(1, 1),
+            "Sign of X",
+            S::sign,
+        ))
+        .register(Operation::new(
+            "min",
+            &[],
+            Arity::new(2, 1),
+            "Smaller of X and Y",
+            S::min,
+        ))
+        .register(Operation::new(
+            "max",
+            &[],
+            Arity::new(2, 1),
+            "Larger of X and Y",
+            S::max,
+        ))
+        .register(Operation::new(
+            "copysign",
+            &[],
+            Arity::new(2, 1),
+            "Magnitude of Y with the sign of X",
+            S::copysign,
+        ))
+        .register(Operation::new(
+            "fma",
+            &[],
+            Arity::new(3, 1),
+            "Fused multiply-add Y * X + Z",
+            S::fma,
+        ))
+    }
+}
+
+impl<S: IntegerMathOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: num_traits::PrimInt + std::fmt::Debug,
+{
+    /// Register the operations of the [IntegerMathOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<u64>>::new();
+    /// registry.register_integer_math_operations();
+    ///
+    /// let binomial = registry.get("ncr").unwrap();
+    /// assert_eq!(binomial.name(), "binomial");
+    /// assert_eq!(binomial.arity(), Arity::new(2, 1));
+    ///
+    /// let mut stack = DynamicSizedStack::<u64>::clone_from_slice(&[49, 6]);
+    /// let res = binomial.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&13_983_816));
+    /// ```
+    pub fn register_integer_math_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "modulo",
+            &["mod"],
+            Arity::new(2, 1),
+            "Y modulo X with the sign of X",
+            S::modulo,
+        ))
+        .register(Operation::new(
+            "rem_euclid",
+            &["rem"],
+            Arity::new(2, 1),
+            "Non-negative remainder of Y divided by X",
+            S::rem_euclid,
+        ))
+        .register(Operation::new(
+            "integer_divide",
+            &["idiv"],
+            Arity::new(2, 1),
+            "Divide Y by X, rounding towards negative infinity",
+            S::integer_divide,
+        ))
+        .register(Operation::new(
+            "gcd",
+            &[],
+            Arity::new(2, 1),
+            "Greatest common divisor of X and Y",
+            S::gcd,
+        ))
+        .register(Operation::new(
+            "lcm",
+            &[],
+            Arity::new(2, 1),
+            "Least common multiple of X and Y",
+            S::lcm,
+        ))
+        .register(Operation::new(
+            "integer_pow",
+            &["ipow"],
+            Arity::new(2, 1),
+            "Raise Y to the non-negative integer power X",
+            S::integer_pow,
+        ))
+        .register(Operation::new(
+            "isqrt",
+            &[],
+            Arity::new(1, 1),
+            "Integer square root of X",
+            S::isqrt,
+        ))
+        .register(Operation::new(
+            "factorial",
+            &["!", "fact"],
+            Arity::new(1, 1),
+            "Factorial of X",
+            S::factorial,
+        ))
+        .register(Operation::new(
+            "binomial",
+            &["comb", "ncr"],
+            Arity::new(2, 1),
+            "Binomial coefficient Y choose X",
+            S::binomial,
+        ))
+    }
+}
+
+impl<S: SpecialFunctions> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: num_traits::Float + std::fmt::Debug,
+{
+    /// Register the operations of the [SpecialFunctions] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<f64>>::new();
+    /// registry.register_special_functions();
+    ///
+    /// let ln_gamma = registry.get("lgamma").unwrap();
+    /// assert_eq!(ln_gamma.name(), "ln_gamma");
+    /// assert_eq!(ln_gamma.arity(), Arity::new(1, 1));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[5.0]);
+    /// let res = ln_gamma.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_approx_eq!(*stack.get(0).unwrap(), 24.0f64.ln());
+    /// ```
+    pub fn register_special_functions(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "sinh",
+            &[],
+            Arity::new(1, 1),
+            "Hyperbolic sine of X",
+            S::sinh,
+        ))
+        .register(Operation::new(
+            "cosh",
+            &[],
+            Arity::new(1, 1),
+            "Hyperbolic cosine of X",
+            S::cosh,
+        ))
+        .register(Operation::new(
+            "tanh",
+            &[],
+            Arity::new(1, 1),
+            "Hyperbolic tangent of X",
+            S::tanh,
+        ))
+        .register(Operation::new(
+            "asinh",
+            &[],
+            Arity::new(1, 1),
+            "Inverse hyperbolic sine of X",
+            S::asinh,
+        ))
+        .register(Operation::new(
+            "acosh",
+            &[],
+            Arity::new(1, 1),
+            "Inverse hyperbolic cosine of X",
+            S::acosh,
+        ))
+        .register(Operation::new(
+            "atanh",
+            &[],
+            Arity::new(1, 1),
+            "Inverse hyperbolic tangent of X",
+            S::atanh,
+        ))
+        .register(Operation::new(
+            "gamma",
+            &[],
+            Arity::new(1, 1),
+            "Gamma function of X",
+            S::gamma,
+        ))
+        .register(Operation::new(
+            "ln_gamma",
+            &["lgamma"],
+            Arity::new(1, 1),
+            "Natural logarithm of the absolute gamma function of X",
+            S::ln_gamma,
+        ))
+        .register(Operation::new(
+            "factorial",
+            &["!", "fact"],
+            Arity::new(1, 1),
+            "Factorial of X, Γ(X + 1) for non-integers",
+            S::factorial,
+        ))
+        .register(Operation::new(
+            "beta",
+            &[],
+            Arity::new(2, 1),
+            "Beta function of X and Y",
+            S::beta,
+        ))
+        .register(Operation::new(
+            "erf",
+            &[],
+            Arity::new(1, 1),
+            "Error function of X",
+            S::erf,
+        ))
+        .register(Operation::new(
+            "erfc",
+            &[],
+            Arity::new(1, 1),
+            "Complementary error function of X",
+            S::erfc,
+        ))
+        .register(Operation::new(
+            "erf_inv",
+            &["erfinv"],
+            Arity::new(1, 1),
+            "Inverse error function of X",
+            S::erf_inv,
+        ))
+        .register(Operation::new(
+            "bessel_j0",
+            &["j0"],
+            Arity::new(1, 1),
+            "Bessel function J0 of X",
+            S::bessel_j0,
+        ))
+        .register(Operation::new(
+            "bessel_j1",
+            &["j1"],
+            Arity::new(1, 1),
+            "Bessel function J1 of X",
+            S::bessel_j1,
+        ))
+        .register(Operation::new(
+            "bessel_y0",
+            &["y0"],
+            Arity::new(1, 1),
+            "Bessel function Y0 of X",
+            S::bessel_y0,
+        ))
+        .register(Operation::new(
+            "bessel_y1",
+            &["y1"],
+            Arity::new(1, 1),
+            "Bessel function Y1 of X",
+            S::bessel_y1,
+        ))
+    }
+}
+
+impl<S: FractionOperations> OperationRegistry<S>
+where
+    <S as InPlaceFnApplication>::Elem: num_traits::Float + std::fmt::Debug,
+{
+    /// Register the operations of the [FractionOperations] trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use smsflib::registry::{Arity, OperationRegistry};
+    ///
+    /// let mut registry = OperationRegistry::<DynamicSizedStack<f64>>::new();
+    /// registry.register_fraction_operations();
+    ///
+    /// let to_fraction = registry.get("->q").unwrap();
+    /// assert_eq!(to_fraction.name(), "to_fraction");
+    /// assert_eq!(to_fraction.arity(), Arity::new(2, 2));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[0.75, 100.0]);
+    /// let res = to_fraction.apply(&mut stack);
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.get(0), Some(&4.0));
+    /// assert_eq!(stack.get(1), Some(&3.0));
+    /// ```
+    pub fn register_fraction_operations(&mut self) -> &mut Self {
+        self.register(Operation::new(
+            "to_fraction",
+            &["->q"],
+            Arity::new(2, 2),
+            "Best fraction approximating Y with a denominator of at most X",
+            S::to_fraction,
+        ))
+    }
+}
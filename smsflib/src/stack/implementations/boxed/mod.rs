@@ -0,0 +1,266 @@
+//! Stack traits for boxed [Stack] trait objects, so that a backend chosen at runtime
+//! can be used like any other stack
+
+use crate::stack::{
+    BasicStackOperations, CalculatorState, ExtendedStackOperations, InPlaceFnApplication,
+    LastXRegister, Stack, Transaction,
+};
+use std::convert::TryFrom;
+
+impl<T> BasicStackOperations for Box<dyn Stack<T> + '_> {
+    type Elem = T;
+
+    fn rotate_up(&mut self) -> Result<(), crate::StackError> {
+        (**self).rotate_up()
+    }
+
+    fn rotate_down(&mut self) -> Result<(), crate::StackError> {
+        (**self).rotate_down()
+    }
+
+    fn swap(&mut self) -> Result<(), crate::StackError> {
+        (**self).swap()
+    }
+
+    fn pop(&mut self) -> Result<T, crate::StackError> {
+        (**self).pop()
+    }
+
+    fn push(&mut self, value: T) -> Result<(), crate::StackError> {
+        (**self).push(value)
+    }
+
+    fn clear(&mut self) -> Result<(), crate::StackError> {
+        (**self).clear()
+    }
+
+    fn drop(&mut self) -> Result<(), crate::StackError> {
+        (**self).drop()
+    }
+}
+
+impl<T> ExtendedStackOperations for Box<dyn Stack<T> + '_> {
+    fn dup(&mut self) -> Result<(), crate::StackError> {
+        (**self).dup()
+    }
+
+    fn dup2(&mut self) -> Result<(), crate::StackError> {
+        (**self).dup2()
+    }
+
+    fn over(&mut self) -> Result<(), crate::StackError> {
+        (**self).over()
+    }
+
+    fn nip(&mut self) -> Result<(), crate::StackError> {
+        (**self).nip()
+    }
+
+    fn tuck(&mut self) -> Result<(), crate::StackError> {
+        (**self).tuck()
+    }
+
+    fn drop2(&mut self) -> Result<(), crate::StackError> {
+        (**self).drop2()
+    }
+
+    fn pick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        (**self).pick(idx)
+    }
+
+    fn unpick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        (**self).unpick(idx)
+    }
+
+    fn roll(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        (**self).roll(idx)
+    }
+
+    fn rolld(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        (**self).rolld(idx)
+    }
+}
+
+impl<'a, T: 'a> Clone for Box<dyn Stack<T> + 'a> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl<'a, T: 'a> Transaction for Box<dyn Stack<T> + 'a> {}
+
+impl<T> CalculatorState for Box<dyn Stack<T> + '_> {
+    fn arithmetic_policy(&self) -> crate::number::ArithmeticPolicy {
+        (**self).arithmetic_policy()
+    }
+
+    fn set_arithmetic_policy(&mut self, policy: crate::number::ArithmeticPolicy) {
+        (**self).set_arithmetic_policy(policy)
+    }
+
+    fn angle_mode(&self) -> crate::number::AngleMode {
+        (**self).angle_mode()
+    }
+
+    fn set_angle_mode(&mut self, mode: crate::number::AngleMode) {
+        (**self).set_angle_mode(mode)
+    }
+}
+
+impl<T> LastXRegister for Box<dyn Stack<T> + '_> {
+    fn last_x_value(&self) -> Option<&T> {
+        (**self).last_x_value()
+    }
+
+    fn last_x(&mut self) -> Result<(), crate::StackError> {
+        (**self).last_x()
+    }
+}
+
+/// # Note
+/// All functions wrap the closure and forward it to the object-safe methods of [Stack].
+impl<T> InPlaceFnApplication for Box<dyn Stack<T> + '_> {
+    type Elem = T;
+
+    fn unary_fn_in_place<U: FnOnce(&mut T)>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut T| {
+            unary_fn(x);
+            Ok(())
+        })
+    }
+
+    fn binary_fn_in_place_first_arg<U: FnOnce(&mut T, &T)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(|x: &mut T, y: &T| {
+            binary_fn(x, y);
+            Ok(())
+        })
+    }
+
+    fn binary_fn_in_place_second_arg<U: FnOnce(&T, &mut T)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(|x: &T, y: &mut T| {
+            binary_fn(x, y);
+            Ok(())
+        })
+    }
+
+    fn try_unary_fn_in_place<U: FnOnce(&mut T) -> Result<(), crate::StackError>>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut unary_fn = Some(unary_fn);
+        (**self).try_unary_fn_in_place_dyn(&mut |x| call_once(&mut unary_fn)(x))
+    }
+
+    fn try_binary_fn_in_place_first_arg<U: FnOnce(&mut T, &T) -> Result<(), crate::StackError>>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut binary_fn = Some(binary_fn);
+        (**self).try_binary_fn_in_place_first_arg_dyn(&mut |x, y| call_once(&mut binary_fn)(x, y))
+    }
+
+    fn try_binary_fn_in_place_second_arg<U: FnOnce(&T, &mut T) -> Result<(), crate::StackError>>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut binary_fn = Some(binary_fn);
+        (**self).try_binary_fn_in_place_second_arg_dyn(&mut |x, y| call_once(&mut binary_fn)(x, y))
+    }
+
+    fn try_binary_fn_keeping_second_arg<U: FnOnce(&mut T, &T) -> Result<(), crate::StackError>>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut binary_fn = Some(binary_fn);
+        (**self).try_binary_fn_keeping_second_arg_dyn(&mut |x, y| call_once(&mut binary_fn)(x, y))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack: Box<dyn Stack<i32>> = Box::new(DynamicSizedStack::clone_from_slice(&[9, 3, 17]));
+    /// let res = stack.nary_fn_in_place(|[x, y]: [i32; 2]| [x % y, x / y]);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&5));
+    /// assert_eq!(stack.get(2), Some(&9));
+    /// ```
+    fn try_nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([T; K]) -> Result<[T; M], crate::StackError>,
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut nary_fn = Some(nary_fn);
+        (**self).try_nary_fn_in_place_dyn(K, &mut |operands| {
+            let operands = match <[T; K]>::try_from(operands) {
+                Ok(operands) => operands,
+                Err(_) => unreachable!("the stack passes exactly K operands"),
+            };
+            call_once(&mut nary_fn)(operands).map(Vec::from)
+        })
+    }
+}
+
+/// Take a closure which the object-safe methods call at most once
+fn call_once<U>(closure: &mut Option<U>) -> U {
+    closure
+        .take()
+        .expect("the stack calls the closure at most once")
+}
+
+impl<T: num_traits::Float + std::fmt::Debug> crate::stack::FloatMathOperations
+    for Box<dyn Stack<T> + '_>
+{
+}
+impl<T: num_traits::Float + std::fmt::Debug> crate::stack::SpecialFunctions
+    for Box<dyn Stack<T> + '_>
+{
+}
+impl<T: num_traits::Float + std::fmt::Debug> crate::number::FractionOperations
+    for Box<dyn Stack<T> + '_>
+{
+}
+impl<T: num_traits::PrimInt + std::fmt::Debug> crate::stack::IntegerMathOperations
+    for Box<dyn Stack<T> + '_>
+{
+}
+impl<T: Clone + num_traits::NumAssignRef + crate::number::OverflowArithmetic + std::fmt::Debug>
+    crate::stack::BasicMathOperations for Box<dyn Stack<T> + '_>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::FromPrimitive
+            + crate::number::OverflowArithmetic
+            + PartialOrd
+            + std::fmt::Debug,
+    > crate::stack::PercentageOperations for Box<dyn Stack<T> + '_>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::Signed
+            + crate::number::OverflowArithmetic
+            + std::fmt::Debug,
+    > crate::stack::SignedMathOperations for Box<dyn Stack<T> + '_>
+{
+}
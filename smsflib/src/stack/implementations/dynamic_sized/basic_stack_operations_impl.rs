@@ -217,6 +217,9 @@ impl<T: Clone> BasicStackOperations for DynamicSizedStack<T> {
 
     /// Push a value into the lowermost register, increasing stack size by one.
     ///
+    /// If the stack is bounded and full, either return [Err] or discard the uppermost register,
+    /// depending on its [StackOverflowPolicy](crate::stack::StackOverflowPolicy).
+    ///
     /// # Example
     ///
     /// ```
@@ -237,7 +240,9 @@ impl<T: Clone> BasicStackOperations for DynamicSizedStack<T> {
     /// ```
     ///
     fn push(&mut self, value: Self::Elem) -> Result<(), crate::StackError> {
+        self.ensure_capacity(1)?;
         self.container.push(value);
+        self.discard_deepest();
         Ok(())
     }
 
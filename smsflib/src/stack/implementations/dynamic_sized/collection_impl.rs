@@ -0,0 +1,140 @@
+use super::DynamicSizedStack;
+
+/// # Note
+/// Stacks are equal if they hold the same levels.
+/// The LastX register and the maximum depth are not compared.
+impl<T: PartialEq> PartialEq for DynamicSizedStack<T> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[1, 2, 3]);
+    /// stack.add();
+    ///
+    /// assert_eq!(stack, DynamicSizedStack::clone_from_slice(&[1, 5]));
+    /// assert_ne!(stack, DynamicSizedStack::clone_from_slice(&[5, 1]));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.container == other.container
+    }
+}
+
+impl<T: Eq> Eq for DynamicSizedStack<T> {}
+
+impl<T: std::hash::Hash> std::hash::Hash for DynamicSizedStack<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.container.hash(state);
+    }
+}
+
+impl<T> std::ops::Index<usize> for DynamicSizedStack<T> {
+    type Output = T;
+
+    /// Get a level by its index, level 0 being the lowermost one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stack has no level `idx`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(stack[0], 3);
+    /// assert_eq!(stack[2], 1);
+    /// ```
+    fn index(&self, idx: usize) -> &T {
+        match self.get(idx) {
+            Some(elem) => elem,
+            None => panic!(
+                "level out of range: the depth is {} but the level is {}",
+                self.len(),
+                idx
+            ),
+        }
+    }
+}
+
+impl<T> IntoIterator for DynamicSizedStack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<std::vec::IntoIter<T>>;
+
+    /// Consume the stack, yielding the levels starting with the lowermost one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.container.into_iter().rev()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DynamicSizedStack<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Rev<std::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # Note
+/// The values are pushed in iteration order, so the last one ends up in the lowermost level.
+/// This is the reverse of the iteration order.
+impl<T> std::iter::FromIterator<T> for DynamicSizedStack<T> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack: DynamicSizedStack<i32> = (1..=3).collect();
+    ///
+    /// assert_eq!(stack, DynamicSizedStack::clone_from_slice(&[1, 2, 3]));
+    /// assert_eq!(stack.iter().rev().copied().collect::<DynamicSizedStack<_>>(), stack);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = DynamicSizedStack::new();
+        stack.container = iter.into_iter().collect();
+        stack
+    }
+}
+
+/// # Note
+/// The values are pushed in iteration order, so the last one ends up in the lowermost level.
+impl<T> Extend<T> for DynamicSizedStack<T> {
+    /// # Panics
+    ///
+    /// Panics if the stack is bounded and its overflow policy is
+    /// [Error](crate::stack::StackOverflowPolicy::Error) when it overflows.
+    /// See [try_extend](DynamicSizedStack::try_extend) for a fallible variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::with_max_depth(3, StackOverflowPolicy::DiscardDeepest);
+    /// stack.extend(vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&4, &3, &2]);
+    ///
+    /// stack.extend(5..=100);
+    ///
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&100, &99, &98]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if let Err(e) = self.try_extend(iter) {
+            panic!("cannot extend the stack: {:?}", e);
+        }
+    }
+}
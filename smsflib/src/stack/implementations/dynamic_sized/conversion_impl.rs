@@ -0,0 +1,32 @@
+use super::DynamicSizedStack;
+use crate::stack::FixedStack;
+
+/// # Note
+/// Every register becomes a level, X being the lowermost one.
+/// The LastX register, the arithmetic policy and the angle mode are kept, the new stack is unbounded.
+impl<T, const N: usize> From<FixedStack<T, N>> for DynamicSizedStack<T> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut classic = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// classic.add();
+    ///
+    /// let stack = DynamicSizedStack::from(classic);
+    ///
+    /// assert_eq!(stack, DynamicSizedStack::clone_from_slice(&[4, 4, 3, 3]));
+    /// assert_eq!(stack.last_x_value(), Some(&1));
+    /// ```
+    fn from(stack: FixedStack<T, N>) -> Self {
+        let (registers, last_x, arithmetic_policy, angle_mode) = stack.into_parts();
+        let mut container = Vec::with_capacity(N);
+        container.extend(IntoIterator::into_iter(registers).rev());
+        let mut stack = DynamicSizedStack::new();
+        stack.container = container;
+        stack.last_x = last_x;
+        stack.arithmetic_policy = arithmetic_policy;
+        stack.angle_mode = angle_mode;
+        stack
+    }
+}
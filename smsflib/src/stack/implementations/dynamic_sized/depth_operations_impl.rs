@@ -0,0 +1,130 @@
+use super::DynamicSizedStack;
+use crate::stack::DepthOperations;
+
+impl<T: Clone> DepthOperations for DynamicSizedStack<T> {
+    /// Get the number of registers, same as [len](DynamicSizedStack::len).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]);
+    ///
+    /// assert_eq!(stack.depth(), 3);
+    /// ```
+    ///
+    fn depth(&self) -> usize {
+        self.len()
+    }
+
+    /// Duplicate the `n` lowermost registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.dupn(3);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 7);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// assert_eq!(stack.get(2), Some(&3));
+    /// assert_eq!(stack.get(3), Some(&1));
+    /// assert_eq!(stack.get(4), Some(&2));
+    /// assert_eq!(stack.get(5), Some(&3));
+    /// assert_eq!(stack.get(6), Some(&4));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.dupn(3);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    fn dupn(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(n)?;
+        self.ensure_capacity(n)?;
+        let first_idx = self.len() - n;
+        self.container.extend_from_within(first_idx..);
+        self.discard_deepest();
+        Ok(())
+    }
+
+    /// Remove the `n` lowermost registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.dropn(3);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// assert_eq!(stack.get(0), Some(&4));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.dropn(3);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    fn dropn(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(n)?;
+        self.container.truncate(self.len() - n);
+        Ok(())
+    }
+
+    /// Keep the `n` lowermost registers, removing all others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.keep(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.keep(3);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    fn keep(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(n)?;
+        let num_dropped = self.len() - n;
+        self.container.drain(..num_dropped);
+        Ok(())
+    }
+}
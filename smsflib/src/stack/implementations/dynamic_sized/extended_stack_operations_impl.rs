@@ -0,0 +1,327 @@
+use super::DynamicSizedStack;
+use crate::stack::{BasicStackOperations, ExtendedStackOperations};
+
+impl<T: Clone> ExtendedStackOperations for DynamicSizedStack<T> {
+    /// Duplicate the lowermost register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.dup();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&1));
+    /// assert_eq!(stack.get(2), Some(&2));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::new();
+    /// let res = stack.dup();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 1, num_available: 0}));
+    /// ```
+    ///
+    fn dup(&mut self) -> Result<(), crate::StackError> {
+        self.pick(0)
+    }
+
+    /// Duplicate the two lowermost registers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]);
+    /// let res = stack.dup2();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 5);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// assert_eq!(stack.get(2), Some(&1));
+    /// assert_eq!(stack.get(3), Some(&2));
+    /// assert_eq!(stack.get(4), Some(&3));
+    /// ```
+    ///
+    fn dup2(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.ensure_capacity(2)?;
+        let idx_penultimate = self.len() - 2;
+        self.container.extend_from_within(idx_penultimate..);
+        self.discard_deepest();
+        Ok(())
+    }
+
+    /// Push a copy of the second lowermost register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.over();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&1));
+    /// assert_eq!(stack.get(2), Some(&2));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[1]);
+    /// let res = stack.over();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    ///
+    fn over(&mut self) -> Result<(), crate::StackError> {
+        self.pick(1)
+    }
+
+    /// Remove the second lowermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]);
+    /// let res = stack.nip();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// ```
+    ///
+    fn nip(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let idx_penultimate = self.len() - 2;
+        self.container.remove(idx_penultimate);
+        Ok(())
+    }
+
+    /// Insert a copy of the lowermost register above the second lowermost one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.tuck();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// assert_eq!(stack.get(2), Some(&1));
+    /// ```
+    ///
+    fn tuck(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.ensure_capacity(1)?;
+        // '.unwrap()' is safe here
+        let ultimate_item = self.container.last().unwrap().clone();
+        let idx_penultimate = self.len() - 2;
+        self.container.insert(idx_penultimate, ultimate_item);
+        self.discard_deepest();
+        Ok(())
+    }
+
+    /// Remove the two lowermost registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]);
+    /// let res = stack.drop2();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// assert_eq!(stack.get(0), Some(&3));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[1]);
+    /// let res = stack.drop2();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+    ///
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    ///
+    fn drop2(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.container.truncate(self.len() - 2);
+        Ok(())
+    }
+
+    /// Push a copy of the register at level `idx`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.pick(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 5);
+    /// assert_eq!(stack.get(0), Some(&3));
+    /// assert_eq!(stack.get(1), Some(&1));
+    /// assert_eq!(stack.get(2), Some(&2));
+    /// assert_eq!(stack.get(3), Some(&3));
+    /// assert_eq!(stack.get(4), Some(&4));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.pick(2);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    fn pick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        let item = self.container[self.len() - 1 - idx].clone();
+        self.push(item)
+    }
+
+    /// Pop the lowermost register and overwrite the register at level `idx` of the remaining stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 9]);
+    /// let res = stack.unpick(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// assert_eq!(stack.get(2), Some(&9));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.unpick(1);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    ///
+    fn unpick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(2))?;
+        // '.unwrap()' is safe here
+        let ultimate_item = self.container.pop().unwrap();
+        let target_idx = self.len() - 1 - idx;
+        self.container[target_idx] = ultimate_item;
+        Ok(())
+    }
+
+    /// Move the register at level `idx` to the lowermost register.
+    ///
+    /// ```text
+    /// d c b a → d b a c   (idx = 2)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.roll(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 4);
+    /// assert_eq!(stack.get(0), Some(&3));
+    /// assert_eq!(stack.get(1), Some(&1));
+    /// assert_eq!(stack.get(2), Some(&2));
+    /// assert_eq!(stack.get(3), Some(&4));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[1]);
+    /// let res = stack.roll(1);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+    /// ```
+    ///
+    fn roll(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        let first_idx = self.len() - 1 - idx;
+        self.container[first_idx..].rotate_left(1);
+        Ok(())
+    }
+
+    /// Move the lowermost register to level `idx`.
+    ///
+    /// ```text
+    /// d c b a → d a c b   (idx = 2)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[4, 3, 2, 1]);
+    /// let res = stack.rolld(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 4);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// assert_eq!(stack.get(2), Some(&1));
+    /// assert_eq!(stack.get(3), Some(&4));
+    /// ```
+    ///
+    fn rolld(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        let first_idx = self.len() - 1 - idx;
+        self.container[first_idx..].rotate_right(1);
+        Ok(())
+    }
+}
@@ -37,7 +37,9 @@ impl<T: Clone> InPlaceFnApplication for DynamicSizedStack<T> {
     ) -> Result<(), crate::StackError> {
         match self.container.last_mut() {
             Some(first_elem_mut_ref) => {
+                let consumed_x = first_elem_mut_ref.clone();
                 unary_fn(first_elem_mut_ref);
+                self.last_x = Some(consumed_x);
                 Ok(())
             }
             None => Err(crate::StackError::NotEnoughOperands {
@@ -81,7 +83,10 @@ impl<T: Clone> InPlaceFnApplication for DynamicSizedStack<T> {
             // '.unwrap()' is safe here
             let idx_penultimate = self.len() - 2;
             let penultimate_item = self.container.remove(idx_penultimate);
-            binary_fn(self.container.last_mut().unwrap(), &penultimate_item);
+            let ultimate_item_mut_ref = self.container.last_mut().unwrap();
+            let consumed_x = ultimate_item_mut_ref.clone();
+            binary_fn(ultimate_item_mut_ref, &penultimate_item);
+            self.last_x = Some(consumed_x);
             Ok(())
         } else {
             Err(crate::StackError::NotEnoughOperands {
@@ -125,6 +130,7 @@ impl<T: Clone> InPlaceFnApplication for DynamicSizedStack<T> {
             // '.unwrap()'s are safe here
             let ultimate_item = self.container.pop().unwrap();
             binary_fn(&ultimate_item, self.container.last_mut().unwrap());
+            self.last_x = Some(ultimate_item);
             Ok(())
         } else {
             Err(crate::StackError::NotEnoughOperands {
@@ -133,4 +139,225 @@ impl<T: Clone> InPlaceFnApplication for DynamicSizedStack<T> {
             })
         }
     }
+
+    /// Appy a fallible unary operation to the lowest register in-place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]);
+    /// let res = stack.try_unary_fn_in_place(|x: &mut u32| {
+    ///     *x = x.checked_sub(2).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, 2]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[1, 2])));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// ```
+    ///
+    fn try_unary_fn_in_place<U: FnOnce(&mut Self::Elem) -> Result<(), crate::StackError>>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(1)?;
+        // '.unwrap()' is safe here
+        let ultimate_item_mut_ref = self.container.last_mut().unwrap();
+        let mut result = ultimate_item_mut_ref.clone();
+        unary_fn(&mut result)?;
+        let consumed_x = std::mem::replace(ultimate_item_mut_ref, result);
+        self.last_x = Some(consumed_x);
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the two lowermost registers,
+    /// consuming them and pushing the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 0, 10]);
+    /// let res = stack.try_binary_fn_in_place_first_arg(|x: &mut u32, y: &u32| {
+    ///     *x = x.checked_div(*y).ok_or_else(|| smsflib::StackError::division_by_zero("divide", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("divide", &[10, 0])));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&10));
+    /// assert_eq!(stack.get(1), Some(&0));
+    /// assert_eq!(stack.get(2), Some(&3));
+    /// ```
+    ///
+    fn try_binary_fn_in_place_first_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        // '.unwrap()' is safe here
+        let idx_penultimate = self.len() - 2;
+        let mut result = self.container.last().unwrap().clone();
+        binary_fn(&mut result, &self.container[idx_penultimate])?;
+        self.container.remove(idx_penultimate);
+        let consumed_x = std::mem::replace(self.container.last_mut().unwrap(), result);
+        self.last_x = Some(consumed_x);
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the two lowermost registers,
+    /// consuming them and pushing the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 20, 10]);
+    /// let res = stack.try_binary_fn_in_place_second_arg(|x: &u32, y: &mut u32| {
+    ///     *y = y.checked_div(*x).ok_or_else(|| smsflib::StackError::division_by_zero("divide", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// ```
+    ///
+    fn try_binary_fn_in_place_second_arg<
+        U: FnOnce(&Self::Elem, &mut Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let idx_penultimate = self.len() - 2;
+        let mut result = self.container[idx_penultimate].clone();
+        // '.unwrap()'s are safe here
+        binary_fn(self.container.last().unwrap(), &mut result)?;
+        let ultimate_item = self.container.pop().unwrap();
+        *self.container.last_mut().unwrap() = result;
+        self.last_x = Some(ultimate_item);
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the two lowermost registers,
+    /// replacing the lowermost one with the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[3, 20, 10]);
+    /// let res = stack.try_binary_fn_keeping_second_arg(|x: &mut u32, y: &u32| {
+    ///     *x = y.checked_sub(*x).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&10));
+    /// assert_eq!(stack.get(1), Some(&20));
+    /// assert_eq!(stack.get(2), Some(&3));
+    /// assert_eq!(stack.last_x_value(), Some(&10));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[10]);
+    /// let res = stack.try_binary_fn_keeping_second_arg(|x: &mut u32, y: &u32| Ok(*x += y));
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{ num_required: 2, num_available: 1 }));
+    /// ```
+    ///
+    fn try_binary_fn_keeping_second_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        // '.unwrap()' is safe here
+        let idx_penultimate = self.len() - 2;
+        let mut result = self.container.last().unwrap().clone();
+        binary_fn(&mut result, &self.container[idx_penultimate])?;
+        let consumed_x = std::mem::replace(self.container.last_mut().unwrap(), result);
+        self.last_x = Some(consumed_x);
+        Ok(())
+    }
+
+    /// Apply a fallible operation consuming the `K` lowermost registers and pushing `M` results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[9, 3, 17]);
+    /// let res = stack.try_nary_fn_in_place(|[x, y]: [u32; 2]| {
+    ///     let quotient = x.checked_div(y).ok_or_else(|| smsflib::StackError::division_by_zero("divmod", &[x, y]))?;
+    ///     Ok([x % y, quotient])
+    /// });
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&5));
+    /// assert_eq!(stack.get(2), Some(&9));
+    /// assert_eq!(stack.last_x_value(), Some(&17));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[9, 0, 17]);
+    /// let res = stack.try_nary_fn_in_place(|[x, y]: [u32; 2]| {
+    ///     let quotient = x.checked_div(y).ok_or_else(|| smsflib::StackError::division_by_zero("divmod", &[x, y]))?;
+    ///     Ok([x % y, quotient])
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("divmod", &[17, 0])));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&17));
+    /// assert_eq!(stack.get(1), Some(&0));
+    /// assert_eq!(stack.get(2), Some(&9));
+    /// ```
+    ///
+    fn try_nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([Self::Elem; K]) -> Result<[Self::Elem; M], crate::StackError>,
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(K)?;
+        self.ensure_capacity(M.saturating_sub(K))?;
+        let operands = std::array::from_fn(|idx| self.container[self.len() - 1 - idx].clone());
+        let consumed_x = operands.first().cloned();
+        let results = nary_fn(operands)?;
+        self.container.truncate(self.len() - K);
+        self.container
+            .extend(IntoIterator::into_iter(results).rev());
+        self.discard_deepest();
+        if consumed_x.is_some() {
+            self.last_x = consumed_x;
+        }
+        Ok(())
+    }
 }
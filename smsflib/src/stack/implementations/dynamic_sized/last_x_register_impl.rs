@@ -0,0 +1,63 @@
+use super::DynamicSizedStack;
+use crate::stack::{BasicStackOperations, LastXRegister};
+
+impl<T: Clone> LastXRegister for DynamicSizedStack<T> {
+    /// Get the X value consumed by the last successful operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[7]);
+    /// let res = stack.add();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{ num_required: 2, num_available: 1 }));
+    /// assert_eq!(stack.last_x_value(), None);
+    ///
+    /// let res = stack.change_sign();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.last_x_value(), Some(&7));
+    /// ```
+    fn last_x_value(&self) -> Option<&Self::Elem> {
+        self.last_x.as_ref()
+    }
+
+    /// Push the value of the LastX register onto the stack.
+    ///
+    /// Return [EmptyLastX](crate::StackError::EmptyLastX) if no operation has been applied yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[10, 2]);
+    /// let res = stack.add();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// let res = stack.last_x();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&12));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[1, 2]);
+    /// let res = stack.last_x();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::EmptyLastX));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    fn last_x(&mut self) -> Result<(), crate::StackError> {
+        match self.last_x.clone() {
+            Some(value) => self.push(value),
+            None => Err(crate::StackError::EmptyLastX),
+        }
+    }
+}
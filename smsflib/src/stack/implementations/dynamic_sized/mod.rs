@@ -1,14 +1,63 @@
 /// Implementation of the [BasicStackOperations](crate::stack::BasicStackOperations) trait
 mod basic_stack_operations_impl;
+/// Implementation of the [CalculatorState](crate::stack::CalculatorState) trait
+mod calculator_state_impl;
+/// Implementations of the std collection traits
+mod collection_impl;
+/// Conversions from the other stack types
+mod conversion_impl;
+/// Implementation of the [DepthOperations](crate::stack::DepthOperations) trait
+mod depth_operations_impl;
+/// Implementation of the [ExtendedStackOperations](crate::stack::ExtendedStackOperations) trait
+mod extended_stack_operations_impl;
 /// Implementation of the [InPlaceFnApplication](crate::stack::InPlaceFnApplication) trait
 mod in_place_fn_application_impl;
+/// Implementation of the [LastXRegister](crate::stack::LastXRegister) trait
+mod last_x_register_impl;
+/// Implementation of the [Stack](crate::stack::Stack) trait
+mod stack_impl;
 /// Data type definitions
 mod types;
 
-pub use types::DynamicSizedStack;
+pub use types::{DynamicSizedStack, StackOverflowPolicy};
 
-impl<T: num_traits::Float> crate::stack::FloatMathOperations for DynamicSizedStack<T> {}
-impl<T: Clone + num_traits::NumAssignRef + num_traits::Signed> crate::stack::BasicMathOperations
+impl<T: Clone> crate::stack::Transaction for DynamicSizedStack<T> {}
+impl<T: num_traits::Float + std::fmt::Debug> crate::stack::FloatMathOperations
     for DynamicSizedStack<T>
 {
 }
+impl<T: num_traits::Float + std::fmt::Debug> crate::stack::SpecialFunctions
+    for DynamicSizedStack<T>
+{
+}
+impl<T: num_traits::Float + std::fmt::Debug> crate::number::FractionOperations
+    for DynamicSizedStack<T>
+{
+}
+impl<T: num_traits::PrimInt + std::fmt::Debug> crate::stack::IntegerMathOperations
+    for DynamicSizedStack<T>
+{
+}
+impl<T: Clone + num_traits::NumAssignRef + crate::number::OverflowArithmetic + std::fmt::Debug>
+    crate::stack::BasicMathOperations for DynamicSizedStack<T>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::FromPrimitive
+            + crate::number::OverflowArithmetic
+            + PartialOrd
+            + std::fmt::Debug,
+    > crate::stack::PercentageOperations for DynamicSizedStack<T>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::Signed
+            + crate::number::OverflowArithmetic
+            + std::fmt::Debug,
+    > crate::stack::SignedMathOperations for DynamicSizedStack<T>
+{
+}
@@ -0,0 +1,82 @@
+use super::DynamicSizedStack;
+use crate::stack::{InPlaceFnApplication, Stack};
+
+impl<T: Clone + std::fmt::Display> Stack<T> for DynamicSizedStack<T> {
+    fn len(&self) -> usize {
+        DynamicSizedStack::len(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        DynamicSizedStack::get(self, idx)
+    }
+
+    fn clone_box<'a>(&self) -> Box<dyn Stack<T> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
+    }
+
+    fn try_unary_fn_in_place_dyn(
+        &mut self,
+        unary_fn: &mut dyn FnMut(&mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(unary_fn)
+    }
+
+    fn try_binary_fn_in_place_first_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(binary_fn)
+    }
+
+    fn try_binary_fn_in_place_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&T, &mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(binary_fn)
+    }
+
+    fn try_binary_fn_keeping_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_keeping_second_arg(binary_fn)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::with_max_depth(3, StackOverflowPolicy::Error);
+    /// stack.push(1);
+    /// stack.push(2);
+    /// let res = stack.try_nary_fn_in_place_dyn(1, &mut |operands| Ok(vec![operands[0]; 3]));
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::StackOverflow { max_depth: 3 }));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// ```
+    fn try_nary_fn_in_place_dyn(
+        &mut self,
+        num_inputs: usize,
+        nary_fn: &mut dyn FnMut(Vec<T>) -> Result<Vec<T>, crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(num_inputs)?;
+        let first_idx = self.len() - num_inputs;
+        let operands: Vec<T> = self.container[first_idx..].iter().rev().cloned().collect();
+        let consumed_x = operands.first().cloned();
+        let results = nary_fn(operands)?;
+        self.ensure_capacity(results.len().saturating_sub(num_inputs))?;
+        self.container.truncate(first_idx);
+        self.container.extend(results.into_iter().rev());
+        self.discard_deepest();
+        if consumed_x.is_some() {
+            self.last_x = consumed_x;
+        }
+        Ok(())
+    }
+}
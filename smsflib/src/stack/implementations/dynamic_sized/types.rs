@@ -1,7 +1,26 @@
+use crate::number::{AngleMode, ArithmeticPolicy};
+
 /// Dynamic-sized RPL-like stack
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DynamicSizedStack<T> {
     pub(super) container: Vec<T>,
+    pub(super) last_x: Option<T>,
+    pub(super) max_depth: Option<usize>,
+    pub(super) overflow_policy: StackOverflowPolicy,
+    pub(super) arithmetic_policy: ArithmeticPolicy,
+    pub(super) angle_mode: AngleMode,
+}
+
+/// What happens when an operation would grow a bounded stack beyond its maximum depth
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StackOverflowPolicy {
+    /// Fail with [StackOverflow](crate::StackError::StackOverflow), leaving the stack unchanged
+    Error,
+    /// Discard the uppermost registers like a ring buffer.
+    ///
+    /// Discarding shifts the remaining registers, so every operation growing a full stack
+    /// takes time linear in the maximum depth.
+    DiscardDeepest,
 }
 
 impl<T> DynamicSizedStack<T> {
@@ -19,9 +38,82 @@ impl<T> DynamicSizedStack<T> {
     pub fn new() -> Self {
         DynamicSizedStack {
             container: Vec::new(),
+            last_x: None,
+            max_depth: None,
+            overflow_policy: StackOverflowPolicy::Error,
+            arithmetic_policy: ArithmeticPolicy::Checked,
+            angle_mode: AngleMode::Radians,
         }
     }
 
+    /// Create a new empty stack which can hold at most `max_depth` elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::with_max_depth(2, StackOverflowPolicy::Error);
+    /// assert_eq!(stack.push(1), Ok(()));
+    /// assert_eq!(stack.push(2), Ok(()));
+    ///
+    /// let res = stack.push(3);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::StackOverflow { max_depth: 2 }));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&1));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::with_max_depth(2, StackOverflowPolicy::DiscardDeepest);
+    /// assert_eq!(stack.push(1), Ok(()));
+    /// assert_eq!(stack.push(2), Ok(()));
+    ///
+    /// let res = stack.push(3);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&3));
+    /// assert_eq!(stack.get(1), Some(&2));
+    /// ```
+    pub fn with_max_depth(max_depth: usize, overflow_policy: StackOverflowPolicy) -> Self {
+        DynamicSizedStack {
+            container: Vec::new(),
+            last_x: None,
+            max_depth: Some(max_depth),
+            overflow_policy,
+            arithmetic_policy: ArithmeticPolicy::Checked,
+            angle_mode: AngleMode::Radians,
+        }
+    }
+
+    /// Get the maximum number of elements, [None] if the stack is unbounded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::new();
+    /// assert_eq!(stack.max_depth(), None);
+    ///
+    /// let stack = DynamicSizedStack::<i32>::with_max_depth(16, StackOverflowPolicy::Error);
+    /// assert_eq!(stack.max_depth(), Some(16));
+    /// ```
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Get the behaviour when the maximum depth is reached
+    pub fn overflow_policy(&self) -> StackOverflowPolicy {
+        self.overflow_policy
+    }
+
     /// Get current stack size
     ///
     /// # Example
@@ -77,6 +169,112 @@ impl<T> DynamicSizedStack<T> {
             None
         }
     }
+
+    /// Iterate over the levels, starting with the lowermost one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// assert_eq!(stack.iter().rev().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    pub fn iter(&self) -> std::iter::Rev<std::slice::Iter<'_, T>> {
+        self.container.iter().rev()
+    }
+
+    /// Push the values in iteration order, so the last one ends up in the lowermost level.
+    ///
+    /// Fail with [StackOverflow](crate::StackError::StackOverflow) if the stack is bounded,
+    /// its overflow policy is [Error](StackOverflowPolicy::Error) and the values do not fit.
+    /// The stack is left unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::with_max_depth(3, StackOverflowPolicy::Error);
+    /// assert_eq!(stack.try_extend(vec![1, 2]), Ok(()));
+    ///
+    /// let res = stack.try_extend(vec![3, 4]);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::StackOverflow { max_depth: 3 }));
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    /// ```
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), crate::StackError> {
+        let original_len = self.len();
+        for value in iter {
+            if let Err(e) = self.ensure_capacity(1) {
+                // Nothing has been discarded, since discarding needs another overflow policy
+                self.container.truncate(original_len);
+                return Err(e);
+            }
+            self.container.push(value);
+            // Discard in batches, since every discard shifts the remaining elements
+            if let Some(max_depth) = self.max_depth {
+                if self.len() > max_depth.saturating_mul(2) {
+                    self.discard_deepest();
+                }
+            }
+        }
+        self.discard_deepest();
+        Ok(())
+    }
+}
+
+impl<T> DynamicSizedStack<T> {
+    /// Split the stack into its levels, starting with the uppermost one, its LastX register,
+    /// its arithmetic policy and its angle mode
+    pub(crate) fn into_parts(self) -> (Vec<T>, Option<T>, ArithmeticPolicy, AngleMode) {
+        (
+            self.container,
+            self.last_x,
+            self.arithmetic_policy,
+            self.angle_mode,
+        )
+    }
+
+    /// Return [Err] if the stack has less than `num_required` elements
+    pub(super) fn ensure_operands(&self, num_required: usize) -> Result<(), crate::StackError> {
+        if self.len() >= num_required {
+            Ok(())
+        } else {
+            Err(crate::StackError::NotEnoughOperands {
+                num_required,
+                num_available: self.len(),
+            })
+        }
+    }
+
+    /// Return [Err] if growing the stack by `num_added` elements would exceed the maximum depth
+    /// and the overflow policy is [Error](StackOverflowPolicy::Error)
+    pub(super) fn ensure_capacity(&self, num_added: usize) -> Result<(), crate::StackError> {
+        match (self.max_depth, self.overflow_policy) {
+            (Some(max_depth), StackOverflowPolicy::Error)
+                if self.len().saturating_add(num_added) > max_depth =>
+            {
+                Err(crate::StackError::StackOverflow { max_depth })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Discard the uppermost elements beyond the maximum depth
+    pub(super) fn discard_deepest(&mut self) {
+        if let Some(max_depth) = self.max_depth {
+            if self.len() > max_depth {
+                let num_discarded = self.len() - max_depth;
+                self.container.drain(..num_discarded);
+            }
+        }
+    }
 }
 
 impl<T> Default for DynamicSizedStack<T> {
@@ -103,6 +301,11 @@ impl<T: Clone> DynamicSizedStack<T> {
     pub fn clone_from_slice(source: &[T]) -> Self {
         DynamicSizedStack {
             container: source.to_vec(),
+            last_x: None,
+            max_depth: None,
+            overflow_policy: StackOverflowPolicy::Error,
+            arithmetic_policy: ArithmeticPolicy::Checked,
+            angle_mode: AngleMode::Radians,
         }
     }
 }
@@ -1,10 +1,10 @@
-use super::types::ClassicStack;
+use super::FixedStack;
 use crate::stack::BasicStackOperations;
 
-impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
-    /// # Note
-    /// All functions always return [Ok], since the stack has fixed size.
-
+/// # Note
+/// All functions except [swap](BasicStackOperations::swap) on a single-register stack
+/// always return [Ok], since the stack has fixed size.
+impl<T: num_traits::Zero + Clone, const N: usize> BasicStackOperations for FixedStack<T, N> {
     type Elem = T;
 
     /// Rotate stack up:
@@ -51,9 +51,7 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// ```
     ///
     fn rotate_up(&mut self) -> Result<(), crate::StackError> {
-        std::mem::swap(&mut self.x, &mut self.y);
-        std::mem::swap(&mut self.x, &mut self.z);
-        std::mem::swap(&mut self.x, &mut self.t);
+        self.registers.rotate_right(1);
         Ok(())
     }
 
@@ -101,9 +99,7 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// ```
     ///
     fn rotate_down(&mut self) -> Result<(), crate::StackError> {
-        std::mem::swap(&mut self.x, &mut self.t);
-        std::mem::swap(&mut self.x, &mut self.z);
-        std::mem::swap(&mut self.x, &mut self.y);
+        self.registers.rotate_left(1);
         Ok(())
     }
 
@@ -151,11 +147,12 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// ```
     ///
     fn swap(&mut self) -> Result<(), crate::StackError> {
-        std::mem::swap(&mut self.x, &mut self.y);
+        self.ensure_operands(2)?;
+        self.registers.swap(0, 1);
         Ok(())
     }
 
-    /// Pop a value from the X register, shifting other registers down and cloning the T register.
+    /// Pop a value from the X register, shifting other registers down and cloning the uppermost register.
     ///
     ///
     /// ```text
@@ -165,7 +162,7 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// X ╰───Y
     /// ↓
     /// ```
-    /// Note: [ClassicStack] will always return a value, signature has a [Result] since a
+    /// Note: [FixedStack] will always return a value, signature has a [Result] since a
     /// [DynamicSizedStack](crate::stack::DynamicSizedStack) can be empty.
     ///
     /// # Example
@@ -183,13 +180,10 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// assert_eq!(*stack.t(), 4);
     /// ```
     fn pop(&mut self) -> Result<Self::Elem, crate::StackError> {
-        Ok(std::mem::replace(
-            &mut self.x,
-            std::mem::replace(&mut self.y, std::mem::replace(&mut self.z, self.t.clone())),
-        ))
+        Ok(self.remove_level(0))
     }
 
-    /// Move a value into the X register, dropping the T register.
+    /// Move a value into the X register, dropping the uppermost register.
     ///
     /// # Example
     ///
@@ -207,10 +201,7 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// assert_eq!(*stack.t(), 3);
     /// ```
     fn push(&mut self, value: Self::Elem) -> Result<(), crate::StackError> {
-        self.t = std::mem::replace(
-            &mut self.z,
-            std::mem::replace(&mut self.y, std::mem::replace(&mut self.x, value)),
-        );
+        self.insert_level(0, value);
         Ok(())
     }
 
@@ -233,10 +224,9 @@ impl<T: num_traits::Zero + Clone> BasicStackOperations for ClassicStack<T> {
     /// ```
     fn clear(&mut self) -> Result<(), crate::StackError> {
         use num_traits::identities::zero;
-        self.x = zero();
-        self.y = zero();
-        self.z = zero();
-        self.t = zero();
+        self.registers
+            .iter_mut()
+            .for_each(|register| *register = zero());
         Ok(())
     }
 }
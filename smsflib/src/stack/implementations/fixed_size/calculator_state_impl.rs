@@ -0,0 +1,21 @@
+use super::FixedStack;
+use crate::number::{AngleMode, ArithmeticPolicy};
+use crate::stack::CalculatorState;
+
+impl<T, const N: usize> CalculatorState for FixedStack<T, N> {
+    fn arithmetic_policy(&self) -> ArithmeticPolicy {
+        self.arithmetic_policy
+    }
+
+    fn set_arithmetic_policy(&mut self, policy: ArithmeticPolicy) {
+        self.arithmetic_policy = policy;
+    }
+
+    fn angle_mode(&self) -> AngleMode {
+        self.angle_mode
+    }
+
+    fn set_angle_mode(&mut self, mode: AngleMode) {
+        self.angle_mode = mode;
+    }
+}
@@ -1,13 +1,9 @@
+use super::FixedStack;
+
 /// Classic HP 35-like stack with a fixed size of four registers: X, Y, Z, T
-#[derive(Debug)]
-pub struct ClassicStack<T> {
-    pub(super) x: T,
-    pub(super) y: T,
-    pub(super) z: T,
-    pub(super) t: T,
-}
+pub type ClassicStack<T> = FixedStack<T, 4>;
 
-impl<T> ClassicStack<T> {
+impl<T> FixedStack<T, 4> {
     /// Create a new stack with given values
     ///
     /// # Example
@@ -23,7 +19,7 @@ impl<T> ClassicStack<T> {
     /// assert_eq!(*stack.t(), 4);
     /// ```
     pub fn new(x: T, y: T, z: T, t: T) -> Self {
-        ClassicStack { x, y, z, t }
+        FixedStack::from_levels([x, y, z, t])
     }
 
     /// Get the X register per reference
@@ -38,7 +34,7 @@ impl<T> ClassicStack<T> {
     /// assert_eq!(*stack.x(), 1);
     /// ```
     pub fn x(&self) -> &T {
-        &self.x
+        &self.registers[0]
     }
 
     /// Get the Y register per reference
@@ -53,7 +49,7 @@ impl<T> ClassicStack<T> {
     /// assert_eq!(*stack.y(), 2);
     /// ```
     pub fn y(&self) -> &T {
-        &self.y
+        &self.registers[1]
     }
 
     /// Get the Z register per reference
@@ -68,7 +64,7 @@ impl<T> ClassicStack<T> {
     /// assert_eq!(*stack.z(), 3);
     /// ```
     pub fn z(&self) -> &T {
-        &self.z
+        &self.registers[2]
     }
 
     /// Get the T register per reference
@@ -83,42 +79,6 @@ impl<T> ClassicStack<T> {
     /// assert_eq!(*stack.t(), 4);
     /// ```
     pub fn t(&self) -> &T {
-        &self.t
-    }
-}
-
-impl<T: num_traits::Zero> ClassicStack<T> {
-    /// Create a new stack filled with zero values
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use smsflib::prelude::*;
-    ///
-    /// let stack = ClassicStack::<i32>::new_zero();
-    ///
-    /// assert_eq!(*stack.x(), 0);
-    /// assert_eq!(*stack.y(), 0);
-    /// assert_eq!(*stack.z(), 0);
-    /// assert_eq!(*stack.t(), 0);
-    /// ```
-    pub fn new_zero() -> Self {
-        use num_traits::identities::zero;
-        ClassicStack {
-            x: zero(),
-            y: zero(),
-            z: zero(),
-            t: zero(),
-        }
-    }
-}
-
-impl<T: std::fmt::Display> std::fmt::Display for ClassicStack<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "T: {}\nZ: {}\nY: {}\nX: {}\n",
-            self.t, self.z, self.y, self.x
-        )
+        &self.registers[3]
     }
 }
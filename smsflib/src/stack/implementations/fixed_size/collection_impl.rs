@@ -0,0 +1,122 @@
+use super::FixedStack;
+
+/// # Note
+/// Stacks are equal if they hold the same registers. The LastX register is not compared.
+impl<T: PartialEq, const N: usize> PartialEq for FixedStack<T, N> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// stack.add();
+    ///
+    /// assert_eq!(stack, ClassicStack::new(3, 3, 4, 4));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.registers == other.registers
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for FixedStack<T, N> {}
+
+impl<T: std::hash::Hash, const N: usize> std::hash::Hash for FixedStack<T, N> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.registers.hash(state);
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<usize> for FixedStack<T, N> {
+    type Output = T;
+
+    /// Get a register by its level, level 0 being X.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(stack[0], 1);
+    /// assert_eq!(stack[3], 4);
+    /// ```
+    fn index(&self, idx: usize) -> &T {
+        &self.registers[idx]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for FixedStack<T, N> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    /// Consume the stack, yielding the registers starting with X.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.registers)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a FixedStack<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # Note
+/// The values are pushed in iteration order onto a stack filled with zero values,
+/// so the last one ends up in X and only the last `N` values are kept.
+impl<T: num_traits::Zero + Clone, const N: usize> std::iter::FromIterator<T> for FixedStack<T, N> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack: ClassicStack<i32> = (1..=3).collect();
+    /// assert_eq!(stack, ClassicStack::new(3, 2, 1, 0));
+    ///
+    /// let stack: ClassicStack<i32> = (1..=6).collect();
+    /// assert_eq!(stack, ClassicStack::new(6, 5, 4, 3));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = FixedStack::new_zero();
+        stack.extend(iter);
+        stack
+    }
+}
+
+/// # Note
+/// The values are pushed in iteration order, dropping the uppermost register each time.
+impl<T: Clone, const N: usize> Extend<T> for FixedStack<T, N> {
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// stack.extend(vec![5, 6]);
+    ///
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&6, &5, &1, &2]);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert_level(0, value);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::{DeepStackPolicy, FixedStack, ShortStackFill};
+use crate::stack::DynamicSizedStack;
+
+impl<T: num_traits::Zero + Clone, const N: usize> FixedStack<T, N> {
+    /// Convert a dynamic-sized stack, keeping its lowermost level in X.
+    ///
+    /// If the stack has less than `N` levels, the registers above them are filled according
+    /// to `fill`. If it has more than `N` levels, the surplus is handled according to `deep`.
+    /// The LastX register, the arithmetic policy and the angle mode are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[2, 1]);
+    ///
+    /// let res = ClassicStack::try_from_dynamic(stack.clone(), ShortStackFill::Zero, DeepStackPolicy::Error);
+    /// assert_eq!(res, Ok(ClassicStack::new(1, 2, 0, 0)));
+    ///
+    /// let res = ClassicStack::try_from_dynamic(stack, ShortStackFill::ReplicateUppermost, DeepStackPolicy::Error);
+    /// assert_eq!(res, Ok(ClassicStack::new(1, 2, 2, 2)));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[6, 5, 4, 3, 2, 1]);
+    ///
+    /// let res = ClassicStack::try_from_dynamic(stack.clone(), ShortStackFill::Zero, DeepStackPolicy::Truncate);
+    /// assert_eq!(res, Ok(ClassicStack::new(1, 2, 3, 4)));
+    ///
+    /// let res = ClassicStack::try_from_dynamic(stack, ShortStackFill::Zero, DeepStackPolicy::Error);
+    /// assert_eq!(res, Err(smsflib::StackError::StackOverflow { max_depth: 4 }));
+    /// ```
+    pub fn try_from_dynamic(
+        stack: DynamicSizedStack<T>,
+        fill: ShortStackFill,
+        deep: DeepStackPolicy,
+    ) -> Result<Self, crate::StackError> {
+        if deep == DeepStackPolicy::Error && stack.len() > N {
+            return Err(crate::StackError::StackOverflow { max_depth: N });
+        }
+        let (container, last_x, arithmetic_policy, angle_mode) = stack.into_parts();
+        let filler = match (fill, container.first()) {
+            (ShortStackFill::ReplicateUppermost, Some(uppermost)) => uppermost.clone(),
+            _ => T::zero(),
+        };
+        let mut levels = container.into_iter().rev();
+        let registers = std::array::from_fn(|_| levels.next().unwrap_or_else(|| filler.clone()));
+        let mut stack = FixedStack::from_levels(registers);
+        stack.last_x = last_x;
+        stack.arithmetic_policy = arithmetic_policy;
+        stack.angle_mode = angle_mode;
+        Ok(stack)
+    }
+}
+
+/// # Note
+/// Short stacks are filled with zero values, deep stacks fail with
+/// [StackOverflow](crate::StackError::StackOverflow).
+/// Use [try_from_dynamic](FixedStack::try_from_dynamic) for other policies.
+impl<T: num_traits::Zero + Clone, const N: usize> std::convert::TryFrom<DynamicSizedStack<T>>
+    for FixedStack<T, N>
+{
+    type Error = crate::StackError;
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// let stack = DynamicSizedStack::<i32>::clone_from_slice(&[3, 2, 1]);
+    ///
+    /// assert_eq!(ClassicStack::try_from(stack.clone()), Ok(ClassicStack::new(1, 2, 3, 0)));
+    /// assert_eq!(
+    ///     FixedStack::<i32, 2>::try_from(stack),
+    ///     Err(smsflib::StackError::StackOverflow { max_depth: 2 })
+    /// );
+    /// ```
+    fn try_from(stack: DynamicSizedStack<T>) -> Result<Self, Self::Error> {
+        FixedStack::try_from_dynamic(stack, ShortStackFill::Zero, DeepStackPolicy::Error)
+    }
+}
@@ -0,0 +1,266 @@
+use super::FixedStack;
+use crate::stack::{BasicStackOperations, ExtendedStackOperations};
+
+/// # Note
+/// Operations growing the stack drop the uppermost register,
+/// operations shrinking it clone the uppermost register.
+/// Operations return [Err] if they need more operands than there are registers.
+impl<T: num_traits::Zero + Clone, const N: usize> ExtendedStackOperations for FixedStack<T, N> {
+    /// Duplicate the X register, dropping the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.dup();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), 1);
+    /// assert_eq!(*stack.z(), 2);
+    /// assert_eq!(*stack.t(), 3);
+    /// ```
+    fn dup(&mut self) -> Result<(), crate::StackError> {
+        self.pick(0)
+    }
+
+    /// Duplicate the X and Y registers, dropping the two uppermost registers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.dup2();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), 2);
+    /// assert_eq!(*stack.z(), 1);
+    /// assert_eq!(*stack.t(), 2);
+    /// ```
+    fn dup2(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.pick(1)?;
+        self.pick(1)
+    }
+
+    /// Push a copy of the Y register, dropping the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.over();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 2);
+    /// assert_eq!(*stack.y(), 1);
+    /// assert_eq!(*stack.z(), 2);
+    /// assert_eq!(*stack.t(), 3);
+    /// ```
+    fn over(&mut self) -> Result<(), crate::StackError> {
+        self.pick(1)
+    }
+
+    /// Remove the Y register, shifting the registers above it down and cloning the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.nip();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn nip(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.remove_level(1);
+        Ok(())
+    }
+
+    /// Insert a copy of the X register above the Y register, dropping the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.tuck();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), 2);
+    /// assert_eq!(*stack.z(), 1);
+    /// assert_eq!(*stack.t(), 3);
+    /// ```
+    fn tuck(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.insert_level(2, self.registers[0].clone());
+        Ok(())
+    }
+
+    /// Remove the X and Y registers, cloning the uppermost register twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.drop2();
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 3);
+    /// assert_eq!(*stack.y(), 4);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn drop2(&mut self) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        self.remove_level(0);
+        self.remove_level(0);
+        Ok(())
+    }
+
+    /// Push a copy of the register at level `idx`, dropping the uppermost register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.pick(3);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 4);
+    /// assert_eq!(*stack.y(), 1);
+    /// assert_eq!(*stack.z(), 2);
+    /// assert_eq!(*stack.t(), 3);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.pick(4);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 5, num_available: 4}));
+    /// assert_eq!(*stack.x(), 1);
+    /// ```
+    fn pick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        self.push(self.registers[idx].clone())
+    }
+
+    /// Pop the X register and overwrite the register at level `idx` of the remaining stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(9, 2, 3, 4);
+    /// let res = stack.unpick(1);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 2);
+    /// assert_eq!(*stack.y(), 9);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.unpick(3);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 5, num_available: 4}));
+    /// assert_eq!(*stack.x(), 1);
+    /// ```
+    fn unpick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(2))?;
+        let value = self.remove_level(0);
+        self.registers[idx] = value;
+        Ok(())
+    }
+
+    /// Move the register at level `idx` to the X register.
+    /// `roll(3)` is the same as [rotate_up](BasicStackOperations::rotate_up).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.roll(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 3);
+    /// assert_eq!(*stack.y(), 1);
+    /// assert_eq!(*stack.z(), 2);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.roll(4);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 5, num_available: 4}));
+    /// ```
+    fn roll(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        self.registers[..=idx].rotate_right(1);
+        Ok(())
+    }
+
+    /// Move the X register to level `idx`.
+    /// `rolld(3)` is the same as [rotate_down](BasicStackOperations::rotate_down).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.rolld(2);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 2);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 1);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn rolld(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.ensure_operands(idx.saturating_add(1))?;
+        self.registers[..=idx].rotate_left(1);
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use super::FixedStack;
+use crate::stack::InPlaceFnApplication;
+
+/// # Note
+/// All functions always return [Ok] if the stack has enough registers for the operands.
+impl<T: Clone, const N: usize> InPlaceFnApplication for FixedStack<T, N> {
+    type Elem = T;
+    /// Appy a unary operation to the X register in-place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.unary_fn_in_place(|x: &mut u32| {*x += 10; } );
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 11);
+    /// assert_eq!(*stack.y(), 2);
+    /// assert_eq!(*stack.z(), 3);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn unary_fn_in_place<U: FnOnce(&mut Self::Elem)>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let consumed_x = self.registers[0].clone();
+        unary_fn(&mut self.registers[0]);
+        self.last_x = Some(consumed_x);
+        Ok(())
+    }
+
+    /// Appy a binary operation to the X and Y registers, consuming them.
+    /// Leave the result in X, shift other registers down, cloning the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(100, 10, 3, 4);
+    /// let res = stack.binary_fn_in_place_first_arg(|x: &mut u32, y: &u32| {*x -= y; } );
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 90);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn binary_fn_in_place_first_arg<U: FnOnce(&mut Self::Elem, &Self::Elem)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let consumed_x = self.registers[0].clone();
+        let (x, registers_above) = self.registers.split_at_mut(1);
+        binary_fn(&mut x[0], &registers_above[0]);
+        self.last_x = Some(consumed_x);
+        self.remove_level(1);
+        Ok(())
+    }
+
+    /// Appy a binary operation to the X and Y registers, consuming them.
+    /// Leave the result in X, shift other registers down, cloning the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(10, 100, 3, 4);
+    /// let res = stack.binary_fn_in_place_second_arg(|x: &u32, y: &mut u32| {*y -= x; } );
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 90);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn binary_fn_in_place_second_arg<U: FnOnce(&Self::Elem, &mut Self::Elem)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let (x, registers_above) = self.registers.split_at_mut(1);
+        binary_fn(&x[0], &mut registers_above[0]);
+        self.last_x = Some(self.remove_level(0));
+        Ok(())
+    }
+
+    /// Appy a fallible unary operation to the X register in-place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
+    /// let res = stack.try_unary_fn_in_place(|x: &mut u32| {
+    ///     *x = x.checked_sub(2).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, 2]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[1, 2])));
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), 2);
+    /// assert_eq!(*stack.z(), 3);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn try_unary_fn_in_place<U: FnOnce(&mut Self::Elem) -> Result<(), crate::StackError>>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        let mut result = self.registers[0].clone();
+        unary_fn(&mut result)?;
+        self.last_x = Some(std::mem::replace(&mut self.registers[0], result));
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the X and Y registers, consuming them.
+    /// Leave the result in X, shift other registers down, cloning the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(10, 100, 3, 4);
+    /// let res = stack.try_binary_fn_in_place_first_arg(|x: &mut u32, y: &u32| {
+    ///     *x = x.checked_sub(*y).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[10, 100])));
+    ///
+    /// assert_eq!(*stack.x(), 10);
+    /// assert_eq!(*stack.y(), 100);
+    /// assert_eq!(*stack.z(), 3);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn try_binary_fn_in_place_first_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let mut result = self.registers[0].clone();
+        binary_fn(&mut result, &self.registers[1])?;
+        self.last_x = Some(std::mem::replace(&mut self.registers[0], result));
+        self.remove_level(1);
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the X and Y registers, consuming them.
+    /// Leave the result in X, shift other registers down, cloning the uppermost register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(10, 100, 3, 4);
+    /// let res = stack.try_binary_fn_in_place_second_arg(|x: &u32, y: &mut u32| {
+    ///     *y = y.checked_sub(*x).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 90);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn try_binary_fn_in_place_second_arg<
+        U: FnOnce(&Self::Elem, &mut Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let mut result = self.registers[1].clone();
+        binary_fn(&self.registers[0], &mut result)?;
+        self.registers[1] = result;
+        self.last_x = Some(self.remove_level(0));
+        Ok(())
+    }
+
+    /// Appy a fallible binary operation to the X and Y registers, replacing X with the result.
+    /// The other registers do not move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(10, 100, 3, 4);
+    /// let res = stack.try_binary_fn_keeping_second_arg(|x: &mut u32, y: &u32| {
+    ///     *x = x.checked_sub(*y).ok_or_else(|| smsflib::StackError::overflow("subtract", &[*x, *y]))?;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[10, 100])));
+    ///
+    /// assert_eq!(*stack.x(), 10);
+    /// assert_eq!(*stack.y(), 100);
+    /// assert_eq!(*stack.z(), 3);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn try_binary_fn_keeping_second_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(2)?;
+        let mut result = self.registers[0].clone();
+        binary_fn(&mut result, &self.registers[1])?;
+        self.last_x = Some(std::mem::replace(&mut self.registers[0], result));
+        Ok(())
+    }
+
+    /// Apply a fallible operation consuming the `K` lowermost registers and pushing `M` results.
+    /// Every consumed register shifts the stack down, cloning the uppermost register,
+    /// every result shifts it up, dropping the uppermost register.
+    ///
+    /// # Examples
+    ///
+    /// Split X into its integer and fractional part:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 2.0, 3.0, 4.0);
+    /// let res = stack.try_nary_fn_in_place(|[x]: [f64; 1]| Ok([x.fract(), x.trunc()]));
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 0.5);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// assert_eq!(stack.last_x_value(), Some(&2.5));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// let res = stack.try_nary_fn_in_place(|[x, y, z]: [i32; 3]| Ok([x + y + z]));
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 6);
+    /// assert_eq!(*stack.y(), 4);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    ///
+    /// The results must fit into the registers:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = FixedStack::<i32, 1>::from_levels([7]);
+    /// let res = stack.try_nary_fn_in_place(|[x]: [i32; 1]| Ok([x / 2, x % 2]));
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::StackOverflow { max_depth: 1 }));
+    /// assert_eq!(stack.get(0), Some(&7));
+    /// ```
+    fn try_nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([Self::Elem; K]) -> Result<[Self::Elem; M], crate::StackError>,
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(K)?;
+        if M > N {
+            return Err(crate::StackError::StackOverflow { max_depth: N });
+        }
+        let operands = std::array::from_fn(|idx| self.registers[idx].clone());
+        let consumed_x = operands.first().cloned();
+        let results = nary_fn(operands)?;
+        for _ in 0..K {
+            self.remove_level(0);
+        }
+        for result in IntoIterator::into_iter(results).rev() {
+            self.insert_level(0, result);
+        }
+        if consumed_x.is_some() {
+            self.last_x = consumed_x;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use super::FixedStack;
+use crate::stack::{BasicStackOperations, LastXRegister};
+
+impl<T: num_traits::Zero + Clone, const N: usize> LastXRegister for FixedStack<T, N> {
+    /// Get the X value consumed by the last successful operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(5, 10, 0, 1);
+    /// assert_eq!(stack.last_x_value(), None);
+    ///
+    /// let res = stack.divide();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.last_x_value(), Some(&5));
+    /// ```
+    fn last_x_value(&self) -> Option<&Self::Elem> {
+        self.last_x.as_ref()
+    }
+
+    /// Push the value of the LastX register, dropping the uppermost register.
+    ///
+    /// Return [EmptyLastX](crate::StackError::EmptyLastX) if no operation has been applied yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(3, 4, 0, 1);
+    /// let res = stack.multiply();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// let res = stack.last_x();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 3);
+    /// assert_eq!(*stack.y(), 12);
+    /// assert_eq!(*stack.z(), 0);
+    /// assert_eq!(*stack.t(), 1);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// let res = stack.last_x();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::EmptyLastX));
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn last_x(&mut self) -> Result<(), crate::StackError> {
+        match self.last_x.clone() {
+            Some(value) => self.push(value),
+            None => Err(crate::StackError::EmptyLastX),
+        }
+    }
+}
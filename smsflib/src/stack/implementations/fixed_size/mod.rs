@@ -0,0 +1,68 @@
+/// Implementation of the [BasicStackOperations](crate::stack::BasicStackOperations) trait
+mod basic_stack_operations_impl;
+/// Implementation of the [CalculatorState](crate::stack::CalculatorState) trait
+mod calculator_state_impl;
+/// Four-register special case
+mod classic;
+/// Implementations of the std collection traits
+mod collection_impl;
+/// Conversions from the other stack types
+mod conversion_impl;
+/// Implementation of the [ExtendedStackOperations](crate::stack::ExtendedStackOperations) trait
+mod extended_stack_operations_impl;
+/// Implementation of the [InPlaceFnApplication](crate::stack::InPlaceFnApplication) trait
+mod in_place_fn_application_impl;
+/// Implementation of the [LastXRegister](crate::stack::LastXRegister) trait
+mod last_x_register_impl;
+/// Implementation of the [Stack](crate::stack::Stack) trait
+mod stack_impl;
+/// Data type definitions
+mod types;
+
+pub use classic::ClassicStack;
+pub use types::{DeepStackPolicy, FixedStack, ShortStackFill};
+
+impl<T: Clone, const N: usize> crate::stack::Transaction for FixedStack<T, N> {}
+impl<T: num_traits::Float + std::fmt::Debug, const N: usize> crate::stack::FloatMathOperations
+    for FixedStack<T, N>
+{
+}
+impl<T: num_traits::Float + std::fmt::Debug, const N: usize> crate::stack::SpecialFunctions
+    for FixedStack<T, N>
+{
+}
+impl<T: num_traits::Float + std::fmt::Debug, const N: usize> crate::number::FractionOperations
+    for FixedStack<T, N>
+{
+}
+impl<T: num_traits::PrimInt + std::fmt::Debug, const N: usize> crate::stack::IntegerMathOperations
+    for FixedStack<T, N>
+{
+}
+impl<
+        T: Clone + num_traits::NumAssignRef + crate::number::OverflowArithmetic + std::fmt::Debug,
+        const N: usize,
+    > crate::stack::BasicMathOperations for FixedStack<T, N>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::FromPrimitive
+            + crate::number::OverflowArithmetic
+            + PartialOrd
+            + std::fmt::Debug,
+        const N: usize,
+    > crate::stack::PercentageOperations for FixedStack<T, N>
+{
+}
+impl<
+        T: Clone
+            + num_traits::NumAssignRef
+            + num_traits::Signed
+            + crate::number::OverflowArithmetic
+            + std::fmt::Debug,
+        const N: usize,
+    > crate::stack::SignedMathOperations for FixedStack<T, N>
+{
+}
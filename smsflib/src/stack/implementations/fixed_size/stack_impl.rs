@@ -0,0 +1,88 @@
+use super::FixedStack;
+use crate::stack::{InPlaceFnApplication, Stack};
+
+impl<T: num_traits::Zero + Clone + std::fmt::Display, const N: usize> Stack<T>
+    for FixedStack<T, N>
+{
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        FixedStack::get(self, idx)
+    }
+
+    fn clone_box<'a>(&self) -> Box<dyn Stack<T> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
+    }
+
+    fn try_unary_fn_in_place_dyn(
+        &mut self,
+        unary_fn: &mut dyn FnMut(&mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(unary_fn)
+    }
+
+    fn try_binary_fn_in_place_first_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(binary_fn)
+    }
+
+    fn try_binary_fn_in_place_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&T, &mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(binary_fn)
+    }
+
+    fn try_binary_fn_keeping_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_keeping_second_arg(binary_fn)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// let res = stack.try_nary_fn_in_place_dyn(2, &mut |operands| Ok(vec![operands[0] + operands[1]]));
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 3);
+    /// assert_eq!(*stack.y(), 3);
+    /// assert_eq!(*stack.z(), 4);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn try_nary_fn_in_place_dyn(
+        &mut self,
+        num_inputs: usize,
+        nary_fn: &mut dyn FnMut(Vec<T>) -> Result<Vec<T>, crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.ensure_operands(num_inputs)?;
+        let operands = self.registers[..num_inputs].to_vec();
+        let consumed_x = operands.first().cloned();
+        let results = nary_fn(operands)?;
+        if results.len() > N {
+            return Err(crate::StackError::StackOverflow { max_depth: N });
+        }
+        for _ in 0..num_inputs {
+            self.remove_level(0);
+        }
+        for result in results.into_iter().rev() {
+            self.insert_level(0, result);
+        }
+        if consumed_x.is_some() {
+            self.last_x = consumed_x;
+        }
+        Ok(())
+    }
+}
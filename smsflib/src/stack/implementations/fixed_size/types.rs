@@ -0,0 +1,236 @@
+use crate::number::{AngleMode, ArithmeticPolicy};
+
+/// HP-like stack with a fixed number of `N` registers.
+///
+/// Register 0 is X. The uppermost register (T for a [ClassicStack](super::ClassicStack))
+/// is duplicated when the stack shifts down and dropped when the stack shifts up.
+///
+/// `N` must be at least one, the constructors fail to compile otherwise.
+///
+/// # Example
+///
+/// An eight-level stack like the "big stack" mode of the DM42:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = FixedStack::<i32, 8>::from_levels([1, 2, 3, 4, 5, 6, 7, 8]);
+///
+/// let res = stack.add();
+/// assert_eq!(res, Ok(()));
+///
+/// assert_eq!(stack.get(0), Some(&3));
+/// assert_eq!(stack.get(1), Some(&3));
+/// assert_eq!(stack.get(5), Some(&7));
+/// assert_eq!(stack.get(6), Some(&8));
+/// assert_eq!(stack.get(7), Some(&8));
+///
+/// let res = stack.push(10);
+/// assert_eq!(res, Ok(()));
+///
+/// assert_eq!(stack.get(0), Some(&10));
+/// assert_eq!(stack.get(1), Some(&3));
+/// assert_eq!(stack.get(7), Some(&8));
+/// ```
+///
+/// A single register cannot hold two operands:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = FixedStack::<i32, 1>::from_levels([1]);
+/// let res = stack.swap();
+///
+/// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedStack<T, const N: usize> {
+    pub(super) registers: [T; N],
+    pub(super) last_x: Option<T>,
+    pub(super) arithmetic_policy: ArithmeticPolicy,
+    pub(super) angle_mode: AngleMode,
+}
+
+/// How the registers above the levels of a shorter stack are filled when converting it
+/// into a [FixedStack]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortStackFill {
+    /// Fill the remaining registers with zero values
+    Zero,
+    /// Replicate the uppermost level like T is replicated when the stack shifts down.
+    /// An empty stack is filled with zero values.
+    ReplicateUppermost,
+}
+
+/// What happens with the levels beyond the uppermost register when converting a deeper stack
+/// into a [FixedStack]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeepStackPolicy {
+    /// Keep the lowermost levels and discard the others
+    Truncate,
+    /// Fail with [StackOverflow](crate::StackError::StackOverflow)
+    Error,
+}
+
+impl<T, const N: usize> FixedStack<T, N> {
+    const NON_EMPTY: () = assert!(N > 0, "a FixedStack needs at least one register");
+
+    /// Create a new stack with given register values, starting with X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = FixedStack::<i32, 8>::from_levels([1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// assert_eq!(stack.len(), 8);
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(7), Some(&8));
+    /// assert_eq!(stack.get(8), None);
+    /// ```
+    pub fn from_levels(registers: [T; N]) -> Self {
+        let () = Self::NON_EMPTY;
+        FixedStack {
+            registers,
+            last_x: None,
+            arithmetic_policy: ArithmeticPolicy::Checked,
+            angle_mode: AngleMode::Radians,
+        }
+    }
+
+    /// Get the number of registers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new_zero();
+    ///
+    /// assert_eq!(stack.len(), 4);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Get a register by its level, level 0 being X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(stack.get(0), Some(&1));
+    /// assert_eq!(stack.get(3), Some(&4));
+    /// assert_eq!(stack.get(4), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.registers.get(idx)
+    }
+
+    /// Iterate over the registers, starting with X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    ///
+    /// assert_eq!(stack.iter().sum::<i32>(), 10);
+    /// assert_eq!(stack.iter().max(), Some(&4));
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.registers.iter()
+    }
+
+    /// Split the stack into its registers, starting with X, its LastX register,
+    /// its arithmetic policy and its angle mode
+    pub(crate) fn into_parts(self) -> ([T; N], Option<T>, ArithmeticPolicy, AngleMode) {
+        (
+            self.registers,
+            self.last_x,
+            self.arithmetic_policy,
+            self.angle_mode,
+        )
+    }
+
+    /// Return [Err] if more operands are required than there are registers
+    pub(super) fn ensure_operands(&self, num_required: usize) -> Result<(), crate::StackError> {
+        if num_required <= N {
+            Ok(())
+        } else {
+            Err(crate::StackError::NotEnoughOperands {
+                num_required,
+                num_available: N,
+            })
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> FixedStack<T, N> {
+    /// Insert a value at level `idx`, shifting the registers above it up and dropping the
+    /// uppermost register. Levels beyond the uppermost register drop the value instead.
+    pub(super) fn insert_level(&mut self, idx: usize, value: T) {
+        if idx < N {
+            self.registers[idx..].rotate_right(1);
+            self.registers[idx] = value;
+        }
+    }
+
+    /// Remove the register at level `idx`, shifting the registers above it down
+    /// and cloning the uppermost register.
+    pub(super) fn remove_level(&mut self, idx: usize) -> T {
+        self.registers[idx..].rotate_left(1);
+        let uppermost_value = self.registers[N.saturating_sub(2).max(idx)].clone();
+        std::mem::replace(&mut self.registers[N - 1], uppermost_value)
+    }
+}
+
+impl<T: num_traits::Zero, const N: usize> FixedStack<T, N> {
+    /// Create a new stack filled with zero values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = ClassicStack::<i32>::new_zero();
+    ///
+    /// assert_eq!(*stack.x(), 0);
+    /// assert_eq!(*stack.y(), 0);
+    /// assert_eq!(*stack.z(), 0);
+    /// assert_eq!(*stack.t(), 0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = FixedStack::<i32, 8>::new_zero();
+    ///
+    /// assert_eq!(stack.get(7), Some(&0));
+    /// ```
+    pub fn new_zero() -> Self {
+        use num_traits::identities::zero;
+        FixedStack::from_levels(std::array::from_fn(|_| zero()))
+    }
+}
+
+impl<T: std::fmt::Display, const N: usize> std::fmt::Display for FixedStack<T, N> {
+    /// Print the registers from the uppermost one down to X.
+    /// The lower four registers are labelled X, Y, Z and T, the ones above them by their number.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const NAMES: [&str; 4] = ["X", "Y", "Z", "T"];
+        for (idx, register) in self.registers.iter().enumerate().rev() {
+            match NAMES.get(idx) {
+                Some(name) => writeln!(f, "{}: {}", name, register)?,
+                None => writeln!(f, "{}: {}", idx + 1, register)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
-mod classic;
+mod boxed;
 mod dynamic_sized;
+mod fixed_size;
+mod undo;
 
-pub use classic::ClassicStack;
-pub use dynamic_sized::DynamicSizedStack;
+pub use dynamic_sized::{DynamicSizedStack, StackOverflowPolicy};
+pub use fixed_size::{ClassicStack, DeepStackPolicy, FixedStack, ShortStackFill};
+pub use undo::UndoStack;
@@ -0,0 +1,69 @@
+use super::UndoStack;
+use crate::stack::BasicStackOperations;
+
+/// # Note
+/// All functions delegate to the wrapped stack and record a history entry on success.
+impl<S: BasicStackOperations + Clone> BasicStackOperations for UndoStack<S> {
+    type Elem = S::Elem;
+
+    fn rotate_up(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::rotate_up)
+    }
+
+    fn rotate_down(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::rotate_down)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u32>::clone_from_slice(&[1]));
+    /// let res = stack.swap();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+    /// assert_eq!(stack.can_undo(), false);
+    /// ```
+    fn swap(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::swap)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u32>::clone_from_slice(&[2, 1]));
+    /// let res = stack.pop();
+    /// assert_eq!(res, Ok(1));
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.inner().len(), 2);
+    /// assert_eq!(stack.inner().get(0), Some(&1));
+    /// ```
+    fn pop(&mut self) -> Result<Self::Elem, crate::StackError> {
+        self.record(S::pop)
+    }
+
+    fn push(&mut self, value: Self::Elem) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.push(value))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(ClassicStack::<u32>::new(1, 2, 3, 4));
+    /// let res = stack.clear();
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.inner().t(), 0);
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(*stack.inner().t(), 4);
+    /// ```
+    fn clear(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::clear)
+    }
+}
@@ -0,0 +1,24 @@
+use super::UndoStack;
+use crate::number::{AngleMode, ArithmeticPolicy};
+use crate::stack::CalculatorState;
+
+/// # Note
+/// All functions delegate to the wrapped stack. Changing the state does not record
+/// a history entry.
+impl<S: CalculatorState> CalculatorState for UndoStack<S> {
+    fn arithmetic_policy(&self) -> ArithmeticPolicy {
+        self.inner.arithmetic_policy()
+    }
+
+    fn set_arithmetic_policy(&mut self, policy: ArithmeticPolicy) {
+        self.inner.set_arithmetic_policy(policy)
+    }
+
+    fn angle_mode(&self) -> AngleMode {
+        self.inner.angle_mode()
+    }
+
+    fn set_angle_mode(&mut self, mode: AngleMode) {
+        self.inner.set_angle_mode(mode)
+    }
+}
@@ -0,0 +1,35 @@
+use super::UndoStack;
+use crate::stack::DepthOperations;
+
+/// # Note
+/// All functions modifying the stack delegate to the wrapped stack and record a history entry on success.
+impl<S: DepthOperations + Clone> DepthOperations for UndoStack<S> {
+    fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    fn dupn(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.dupn(n))
+    }
+
+    fn dropn(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.dropn(n))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]));
+    /// let res = stack.keep(1);
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.depth(), 1);
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.depth(), 3);
+    /// ```
+    fn keep(&mut self, n: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.keep(n))
+    }
+}
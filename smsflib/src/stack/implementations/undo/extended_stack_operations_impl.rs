@@ -0,0 +1,60 @@
+use super::UndoStack;
+use crate::stack::ExtendedStackOperations;
+
+/// # Note
+/// All functions delegate to the wrapped stack and record a history entry on success.
+impl<S: ExtendedStackOperations + Clone> ExtendedStackOperations for UndoStack<S> {
+    fn dup(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::dup)
+    }
+
+    fn dup2(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::dup2)
+    }
+
+    fn over(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::over)
+    }
+
+    fn nip(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::nip)
+    }
+
+    fn tuck(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::tuck)
+    }
+
+    fn drop2(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::drop2)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u32>::clone_from_slice(&[3, 2, 1]));
+    /// let res = stack.pick(2);
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(stack.inner().get(0), Some(&3));
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.inner().len(), 3);
+    /// assert_eq!(stack.inner().get(0), Some(&1));
+    /// ```
+    fn pick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.pick(idx))
+    }
+
+    fn unpick(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.unpick(idx))
+    }
+
+    fn roll(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.roll(idx))
+    }
+
+    fn rolld(&mut self, idx: usize) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.rolld(idx))
+    }
+}
@@ -0,0 +1,110 @@
+use super::UndoStack;
+use crate::stack::InPlaceFnApplication;
+
+/// # Note
+/// All functions delegate to the wrapped stack and record a history entry on success.
+impl<S: InPlaceFnApplication + Clone> InPlaceFnApplication for UndoStack<S> {
+    type Elem = S::Elem;
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(ClassicStack::<u32>::new(1, 2, 3, 4));
+    /// let res = stack.unary_fn_in_place(|x: &mut u32| {*x += 10; } );
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.inner().x(), 11);
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(*stack.inner().x(), 1);
+    /// ```
+    fn unary_fn_in_place<U: FnOnce(&mut Self::Elem)>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.unary_fn_in_place(unary_fn))
+    }
+
+    fn binary_fn_in_place_first_arg<U: FnOnce(&mut Self::Elem, &Self::Elem)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.binary_fn_in_place_first_arg(binary_fn))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u32>::clone_from_slice(&[10]));
+    /// let res = stack.binary_fn_in_place_second_arg(|x: &u32, y: &mut u32| {*y /= x; } );
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{ num_required: 2, num_available: 1 }));
+    /// assert_eq!(stack.can_undo(), false);
+    /// ```
+    fn binary_fn_in_place_second_arg<U: FnOnce(&Self::Elem, &mut Self::Elem)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.binary_fn_in_place_second_arg(binary_fn))
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(ClassicStack::<f64>::new(-1.0, 2.0, 3.0, 4.0));
+    /// let res = stack.ln();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("ln", &[-1.0])));
+    /// assert_eq!(*stack.inner().x(), -1.0);
+    /// assert_eq!(stack.can_undo(), false);
+    /// ```
+    fn try_unary_fn_in_place<U: FnOnce(&mut Self::Elem) -> Result<(), crate::StackError>>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_unary_fn_in_place(unary_fn))
+    }
+
+    fn try_binary_fn_in_place_first_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_in_place_first_arg(binary_fn))
+    }
+
+    fn try_binary_fn_in_place_second_arg<
+        U: FnOnce(&Self::Elem, &mut Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_in_place_second_arg(binary_fn))
+    }
+
+    fn try_binary_fn_keeping_second_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_keeping_second_arg(binary_fn))
+    }
+
+    fn try_nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([Self::Elem; K]) -> Result<[Self::Elem; M], crate::StackError>,
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_nary_fn_in_place(nary_fn))
+    }
+}
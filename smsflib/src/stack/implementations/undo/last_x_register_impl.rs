@@ -0,0 +1,30 @@
+use super::UndoStack;
+use crate::stack::LastXRegister;
+
+/// # Note
+/// Recalling LastX is recorded in the history like any other operation.
+impl<S: LastXRegister + Clone> LastXRegister for UndoStack<S> {
+    fn last_x_value(&self) -> Option<&Self::Elem> {
+        self.inner.last_x_value()
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(ClassicStack::<i32>::new(2, 3, 0, 0));
+    /// let res = stack.add();
+    /// assert_eq!(res, Ok(()));
+    /// let res = stack.last_x();
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.inner().x(), 2);
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(*stack.inner().x(), 5);
+    /// assert_eq!(stack.last_x_value(), Some(&2));
+    /// ```
+    fn last_x(&mut self) -> Result<(), crate::StackError> {
+        self.record(S::last_x)
+    }
+}
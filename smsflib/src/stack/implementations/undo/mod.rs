@@ -0,0 +1,69 @@
+/// Implementation of the [BasicStackOperations](crate::stack::BasicStackOperations) trait
+mod basic_stack_operations_impl;
+/// Implementation of the [CalculatorState](crate::stack::CalculatorState) trait
+mod calculator_state_impl;
+/// Implementation of the [DepthOperations](crate::stack::DepthOperations) trait
+mod depth_operations_impl;
+/// Implementation of the [ExtendedStackOperations](crate::stack::ExtendedStackOperations) trait
+mod extended_stack_operations_impl;
+/// Implementation of the [InPlaceFnApplication](crate::stack::InPlaceFnApplication) trait
+mod in_place_fn_application_impl;
+/// Implementation of the [LastXRegister](crate::stack::LastXRegister) trait
+mod last_x_register_impl;
+/// Implementation of the [Stack](crate::stack::Stack) trait
+mod stack_impl;
+/// Data type definitions
+mod types;
+
+pub use types::UndoStack;
+
+impl<S: Clone> crate::stack::Transaction for UndoStack<S> {}
+impl<S: crate::stack::FloatMathOperations + Clone> crate::stack::FloatMathOperations
+    for UndoStack<S>
+where
+    S::Elem: num_traits::Float + std::fmt::Debug,
+{
+}
+impl<S: crate::stack::InPlaceFnApplication + Clone> crate::stack::SpecialFunctions for UndoStack<S> where
+    S::Elem: num_traits::Float + std::fmt::Debug
+{
+}
+impl<S: crate::stack::InPlaceFnApplication + Clone> crate::number::FractionOperations
+    for UndoStack<S>
+where
+    S::Elem: num_traits::Float + std::fmt::Debug,
+{
+}
+impl<S: crate::stack::InPlaceFnApplication + Clone> crate::stack::IntegerMathOperations
+    for UndoStack<S>
+where
+    S::Elem: num_traits::PrimInt + std::fmt::Debug,
+{
+}
+impl<S: crate::stack::BasicMathOperations + Clone> crate::stack::BasicMathOperations
+    for UndoStack<S>
+where
+    S::Elem: Clone + num_traits::NumAssignRef + crate::number::OverflowArithmetic + std::fmt::Debug,
+{
+}
+impl<S: crate::stack::BasicMathOperations + Clone> crate::stack::PercentageOperations
+    for UndoStack<S>
+where
+    S::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::FromPrimitive
+        + crate::number::OverflowArithmetic
+        + PartialOrd
+        + std::fmt::Debug,
+{
+}
+impl<S: crate::stack::SignedMathOperations + Clone> crate::stack::SignedMathOperations
+    for UndoStack<S>
+where
+    S::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::Signed
+        + crate::number::OverflowArithmetic
+        + std::fmt::Debug,
+{
+}
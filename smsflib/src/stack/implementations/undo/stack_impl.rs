@@ -0,0 +1,58 @@
+use super::UndoStack;
+use crate::stack::Stack;
+
+/// # Note
+/// All functions delegate to the wrapped stack. Functions changing the levels record a history
+/// entry on success.
+impl<T, S: Stack<T> + Clone> Stack<T> for UndoStack<S> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        self.inner.get(idx)
+    }
+
+    fn clone_box<'a>(&self) -> Box<dyn Stack<T> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
+    }
+
+    fn try_unary_fn_in_place_dyn(
+        &mut self,
+        unary_fn: &mut dyn FnMut(&mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_unary_fn_in_place_dyn(unary_fn))
+    }
+
+    fn try_binary_fn_in_place_first_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_in_place_first_arg_dyn(binary_fn))
+    }
+
+    fn try_binary_fn_in_place_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&T, &mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_in_place_second_arg_dyn(binary_fn))
+    }
+
+    fn try_binary_fn_keeping_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_binary_fn_keeping_second_arg_dyn(binary_fn))
+    }
+
+    fn try_nary_fn_in_place_dyn(
+        &mut self,
+        num_inputs: usize,
+        nary_fn: &mut dyn FnMut(Vec<T>) -> Result<Vec<T>, crate::StackError>,
+    ) -> Result<(), crate::StackError> {
+        self.record(|inner| inner.try_nary_fn_in_place_dyn(num_inputs, nary_fn))
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+/// Wrapper recording the history of another stack, so that operations can be undone and redone
+///
+/// Every successful operation stores a snapshot of the wrapped stack.
+/// Failed operations do not create a history entry.
+#[derive(Debug, Clone)]
+pub struct UndoStack<S> {
+    pub(super) inner: S,
+    pub(super) undo_history: VecDeque<S>,
+    pub(super) redo_history: Vec<S>,
+    pub(super) history_depth: usize,
+}
+
+impl<S> UndoStack<S> {
+    /// Default maximum number of undoable operations
+    pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+    /// Wrap a stack, keeping up to [DEFAULT_HISTORY_DEPTH](Self::DEFAULT_HISTORY_DEPTH)
+    /// undoable operations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let stack = UndoStack::new(ClassicStack::<i32>::new(1, 2, 3, 4));
+    ///
+    /// assert_eq!(stack.can_undo(), false);
+    /// assert_eq!(stack.can_redo(), false);
+    /// assert_eq!(*stack.inner().x(), 1);
+    /// ```
+    pub fn new(inner: S) -> Self {
+        UndoStack::with_history_depth(inner, Self::DEFAULT_HISTORY_DEPTH)
+    }
+
+    /// Wrap a stack, keeping up to `history_depth` undoable operations.
+    /// The oldest entry is discarded when the history is full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::with_history_depth(DynamicSizedStack::<i32>::new(), 2);
+    /// for value in 1..=3 {
+    ///     let res = stack.push(value);
+    ///     assert_eq!(res, Ok(()));
+    /// }
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.undo(), false);
+    ///
+    /// assert_eq!(stack.inner().len(), 1);
+    /// assert_eq!(stack.inner().get(0), Some(&1));
+    /// ```
+    pub fn with_history_depth(inner: S, history_depth: usize) -> Self {
+        UndoStack {
+            inner,
+            undo_history: VecDeque::new(),
+            redo_history: Vec::new(),
+            history_depth,
+        }
+    }
+
+    /// Get the wrapped stack per reference
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the stack, discarding the history
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Get the maximum number of undoable operations
+    pub fn history_depth(&self) -> usize {
+        self.history_depth
+    }
+
+    /// Check if there is an operation to undo
+    pub fn can_undo(&self) -> bool {
+        !self.undo_history.is_empty()
+    }
+
+    /// Check if there is an undone operation to redo
+    pub fn can_redo(&self) -> bool {
+        !self.redo_history.is_empty()
+    }
+
+    /// Revert the last operation.
+    ///
+    /// Return `false` if there is nothing to undo.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(ClassicStack::<i32>::new(1, 2, 3, 4));
+    /// let res = stack.add();
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.inner().x(), 3);
+    ///
+    /// assert_eq!(stack.undo(), true);
+    ///
+    /// assert_eq!(*stack.inner().x(), 1);
+    /// assert_eq!(*stack.inner().y(), 2);
+    /// assert_eq!(*stack.inner().z(), 3);
+    /// assert_eq!(*stack.inner().t(), 4);
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        match self.undo_history.pop_back() {
+            Some(previous) => {
+                self.redo_history
+                    .push(std::mem::replace(&mut self.inner, previous));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Repeat the last undone operation.
+    ///
+    /// Return `false` if there is nothing to redo.
+    /// Any new operation after an undo discards the operations available for redo.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<i32>::clone_from_slice(&[2, 3]));
+    /// let res = stack.multiply();
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.undo(), true);
+    /// assert_eq!(stack.inner().len(), 2);
+    ///
+    /// assert_eq!(stack.redo(), true);
+    /// assert_eq!(stack.redo(), false);
+    /// assert_eq!(stack.inner().len(), 1);
+    /// assert_eq!(stack.inner().get(0), Some(&6));
+    /// ```
+    pub fn redo(&mut self) -> bool {
+        match self.redo_history.pop() {
+            Some(next) => {
+                let previous = std::mem::replace(&mut self.inner, next);
+                self.push_undo_entry(previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget all undoable and redoable operations
+    pub fn clear_history(&mut self) {
+        self.undo_history.clear();
+        self.redo_history.clear();
+    }
+
+    fn push_undo_entry(&mut self, snapshot: S) {
+        if self.history_depth == 0 {
+            return;
+        }
+        if self.undo_history.len() == self.history_depth {
+            self.undo_history.pop_front();
+        }
+        self.undo_history.push_back(snapshot);
+    }
+}
+
+impl<S: Clone> UndoStack<S> {
+    /// Apply an operation to the wrapped stack, recording a history entry if it succeeds
+    pub(super) fn record<R, F: FnOnce(&mut S) -> Result<R, crate::StackError>>(
+        &mut self,
+        operation: F,
+    ) -> Result<R, crate::StackError> {
+        let snapshot = self.inner.clone();
+        let res = operation(&mut self.inner)?;
+        self.push_undo_entry(snapshot);
+        self.redo_history.clear();
+        Ok(res)
+    }
+}
+
+impl<S: std::fmt::Display> std::fmt::Display for UndoStack<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
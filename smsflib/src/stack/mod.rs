@@ -1,8 +1,14 @@
 mod implementations;
 mod traits;
 
-pub use crate::stack::implementations::{ClassicStack, DynamicSizedStack};
+pub use crate::stack::implementations::{
+    ClassicStack, DeepStackPolicy, DynamicSizedStack, FixedStack, ShortStackFill,
+    StackOverflowPolicy, UndoStack,
+};
 
 pub use crate::stack::traits::{
-    BasicMathOperations, BasicStackOperations, FloatMathOperations, InPlaceFnApplication,
+    BasicMathOperations, BasicStackOperations, CalculatorState, DepthOperations,
+    ExtendedStackOperations, FloatMathOperations, InPlaceFnApplication, IntegerMathOperations,
+    LastXRegister, PercentageOperations, SignedMathOperations, SpecialFunctions, Stack,
+    Transaction,
 };
@@ -1,9 +1,15 @@
-use crate::stack::InPlaceFnApplication;
-use num_traits::Signed;
+use crate::number::OverflowArithmetic;
+use crate::stack::{CalculatorState, InPlaceFnApplication};
+use num_traits::Zero;
 
-pub trait BasicMathOperations: InPlaceFnApplication
+/// Arithmetic for signed and unsigned element types.
+///
+/// Operations on signed types only are provided by
+/// [SignedMathOperations](crate::stack::SignedMathOperations).
+pub trait BasicMathOperations: InPlaceFnApplication + CalculatorState
 where
-    <Self as InPlaceFnApplication>::Elem: Clone + num_traits::NumAssignRef + num_traits::Signed,
+    <Self as InPlaceFnApplication>::Elem:
+        Clone + num_traits::NumAssignRef + OverflowArithmetic + std::fmt::Debug,
 {
     /// # Example
     ///
@@ -45,10 +51,14 @@ where
     /// ```
     ///
     fn add(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_first_arg(
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_in_place_first_arg(
             |x: &mut <Self as InPlaceFnApplication>::Elem,
              y: &<Self as InPlaceFnApplication>::Elem| {
-                *x += y;
+                *x = x
+                    .add_with(y, policy)
+                    .ok_or_else(|| crate::StackError::overflow("add", &[&*x, y]))?;
+                Ok(())
             },
         )
     }
@@ -66,11 +76,33 @@ where
     /// assert_eq!(*stack.z(), 1);
     /// assert_eq!(*stack.t(), 1);
     /// ```
+    ///
+    /// Unsigned results below zero are reported like any other overflow:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(1, 2, 0, 0);
+    /// let res = stack.subtract();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("subtract", &[1, 2])));
+    /// assert_eq!(*stack.x(), 1);
+    ///
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Saturating);
+    /// let res = stack.subtract();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0);
+    /// ```
     fn subtract(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_first_arg(
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_in_place_first_arg(
             |x: &mut <Self as InPlaceFnApplication>::Elem,
              y: &<Self as InPlaceFnApplication>::Elem| {
-                *x -= y;
+                *x = x
+                    .sub_with(y, policy)
+                    .ok_or_else(|| crate::StackError::overflow("subtract", &[&*x, y]))?;
+                Ok(())
             },
         )
     }
@@ -89,10 +121,14 @@ where
     /// assert_eq!(*stack.t(), 1);
     /// ```
     fn multiply(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_first_arg(
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_in_place_first_arg(
             |x: &mut <Self as InPlaceFnApplication>::Elem,
              y: &<Self as InPlaceFnApplication>::Elem| {
-                *x *= y;
+                *x = x
+                    .mul_with(y, policy)
+                    .ok_or_else(|| crate::StackError::overflow("multiply", &[&*x, y]))?;
+                Ok(())
             },
         )
     }
@@ -110,50 +146,48 @@ where
     /// assert_eq!(*stack.z(), 1);
     /// assert_eq!(*stack.t(), 1);
     /// ```
-    fn divide(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_second_arg(
-            |x: &<Self as InPlaceFnApplication>::Elem,
-             y: &mut <Self as InPlaceFnApplication>::Elem| {
-                *y /= x;
-            },
-        )
-    }
-
-    /// # Example
+    ///
+    /// Division by zero leaves the stack unchanged, also for floating-point numbers:
     ///
     /// ```
     /// use smsflib::prelude::*;
     ///
-    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
-    /// stack.change_sign();
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[1.0, 0.0]);
+    /// let res = stack.divide();
     ///
-    /// assert_eq!(*stack.x(), -1);
-    /// assert_eq!(*stack.y(), 2);
-    /// assert_eq!(*stack.z(), 3);
-    /// assert_eq!(*stack.t(), 4);
-    /// ```
-    fn change_sign(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = -x.clone();
-        })
-    }
-
-    /// # Example
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("divide", &[0.0, 1.0])));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
     ///
     /// ```
     /// use smsflib::prelude::*;
     ///
-    /// let mut stack = ClassicStack::<i32>::new(-1, -2, -3, -4);
-    /// stack.absolute_value();
+    /// let mut stack = ClassicStack::<i32>::new(0, 7, 0, 0);
+    /// let res = stack.divide();
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("divide", &[0, 7])));
     ///
-    /// assert_eq!(*stack.x(), 1);
-    /// assert_eq!(*stack.y(), -2);
-    /// assert_eq!(*stack.z(), -3);
-    /// assert_eq!(*stack.t(), -4);
-    /// ```
-    fn absolute_value(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.abs();
-        })
+    /// let mut stack = ClassicStack::<i32>::new(-1, i32::MIN, 0, 0);
+    /// let res = stack.divide();
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("divide", &[-1, i32::MIN])));
+    ///
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    /// let res = stack.divide();
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), i32::MIN);
+    /// ```
+    fn divide(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if x.is_zero() {
+                    return Err(crate::StackError::division_by_zero("divide", &[x, &*y]));
+                }
+                *y = y
+                    .div_with(x, policy)
+                    .ok_or_else(|| crate::StackError::overflow("divide", &[x, &*y]))?;
+                Ok(())
+            },
+        )
     }
 }
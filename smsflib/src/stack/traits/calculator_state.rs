@@ -0,0 +1,92 @@
+use crate::number::{AngleMode, ArithmeticPolicy};
+
+/// Calculator state which changes how the math traits compute their results.
+///
+/// The state belongs to the stack, so that it survives conversions between stack types
+/// and is shared by all operations applied to the stack.
+pub trait CalculatorState {
+    /// Get the policy for results which do not fit into an integer element type.
+    ///
+    /// Stacks default to [Checked](ArithmeticPolicy::Checked), so that overflows
+    /// are reported as [Overflow](crate::StackError::Overflow) errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i8>::new(100, 100, 0, 0);
+    /// assert_eq!(stack.arithmetic_policy(), ArithmeticPolicy::Checked);
+    ///
+    /// let res = stack.add();
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("add", &[100, 100])));
+    /// assert_eq!(*stack.x(), 100);
+    ///
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Saturating);
+    /// let res = stack.add();
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 127);
+    /// ```
+    fn arithmetic_policy(&self) -> ArithmeticPolicy;
+
+    /// Set the policy for results which do not fit into an integer element type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = UndoStack::new(DynamicSizedStack::<u8>::clone_from_slice(&[200, 100]));
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    ///
+    /// assert_eq!(stack.arithmetic_policy(), ArithmeticPolicy::Wrapping);
+    /// assert_eq!(stack.add(), Ok(()));
+    /// assert_eq!(stack.inner().get(0), Some(&44));
+    /// ```
+    fn set_arithmetic_policy(&mut self, policy: ArithmeticPolicy);
+
+    /// Get the unit of angles taken and returned by the trigonometric operations.
+    ///
+    /// Stacks default to [Radians](AngleMode::Radians).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.0, 1.0, 0.0, 0.0);
+    /// assert_eq!(stack.angle_mode(), AngleMode::Radians);
+    ///
+    /// stack.set_angle_mode(AngleMode::Turns);
+    /// stack.atan2();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.125);
+    ///
+    /// let stack = UndoStack::new(DynamicSizedStack::<f64>::new());
+    /// assert_eq!(stack.angle_mode(), AngleMode::Radians);
+    /// ```
+    fn angle_mode(&self) -> AngleMode;
+
+    /// Set the unit of angles taken and returned by the trigonometric operations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[30.0]);
+    /// stack.set_angle_mode(AngleMode::Degrees);
+    /// stack.sin();
+    ///
+    /// assert_approx_eq!(*stack.get(0).unwrap(), 0.5);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 0.0, 0.0, 0.0);
+    /// stack.set_angle_mode(AngleMode::Gradians);
+    /// stack.asin();
+    ///
+    /// assert_approx_eq!(*stack.x(), 100.0 / 3.0);
+    /// ```
+    fn set_angle_mode(&mut self, mode: AngleMode);
+}
@@ -0,0 +1,21 @@
+use crate::stack::ExtendedStackOperations;
+
+/// Stack manipulation depending on the current depth of a stack, which is only meaningful
+/// for stacks that can grow and shrink.
+pub trait DepthOperations: ExtendedStackOperations {
+    /// Get the number of levels on the stack
+    fn depth(&self) -> usize;
+
+    /// Duplicate the `n` lowermost levels, keeping their order.
+    ///
+    /// ```text
+    /// c b a → c b a b a   (n = 2)
+    /// ```
+    fn dupn(&mut self, n: usize) -> Result<(), crate::StackError>;
+
+    /// Drop the `n` lowermost levels.
+    fn dropn(&mut self, n: usize) -> Result<(), crate::StackError>;
+
+    /// Keep the `n` lowermost levels, dropping all others.
+    fn keep(&mut self, n: usize) -> Result<(), crate::StackError>;
+}
@@ -0,0 +1,111 @@
+use crate::stack::{BasicStackOperations, CalculatorState, ExtendedStackOperations, LastXRegister};
+
+/// Object-safe stack interface, so that the backend can be chosen at runtime.
+///
+/// The closures of [InPlaceFnApplication](crate::stack::InPlaceFnApplication) are generic
+/// parameters, hence the trait cannot be used as a `dyn` object. This trait takes them as
+/// trait objects instead. `Box<dyn Stack<T>>` implements
+/// [InPlaceFnApplication](crate::stack::InPlaceFnApplication) on top of these methods,
+/// as well as the math traits.
+///
+/// The closures are called at most once.
+///
+/// # Example
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// fn new_stack(classic: bool) -> Box<dyn Stack<f64>> {
+///     if classic {
+///         Box::new(ClassicStack::new_zero())
+///     } else {
+///         Box::new(DynamicSizedStack::new())
+///     }
+/// }
+///
+/// for classic in [false, true] {
+///     let mut stack = new_stack(classic);
+///     assert_eq!(stack.push(2.0), Ok(()));
+///     assert_eq!(stack.push(3.0), Ok(()));
+///     assert_eq!(stack.multiply(), Ok(()));
+///     assert_eq!(stack.sin(), Ok(()));
+///
+///     assert_eq!(stack.get(0), Some(&6.0_f64.sin()));
+///     assert_eq!(stack.last_x_value(), Some(&6.0));
+/// }
+/// ```
+pub trait Stack<T>:
+    BasicStackOperations<Elem = T>
+    + ExtendedStackOperations
+    + LastXRegister
+    + CalculatorState
+    + std::fmt::Display
+{
+    /// Get the number of levels
+    fn len(&self) -> usize;
+
+    /// Check if the stack has no levels
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a level by its index, level 0 being X
+    fn get(&self, idx: usize) -> Option<&T>;
+
+    /// Clone the stack into a new box, so that boxed stacks implement [Clone]
+    /// and [Transaction](crate::stack::Transaction).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack: Box<dyn Stack<f64>> = Box::new(ClassicStack::new(1.0, 2.0, 3.0, 4.0));
+    /// let res = stack.transaction(|s| {
+    ///     s.add()?;
+    ///     s.push(-1.0)?;
+    ///     s.ln()
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("ln", &[-1.0])));
+    /// assert_eq!(stack.get(0), Some(&1.0));
+    /// assert_eq!(stack.get(1), Some(&2.0));
+    /// ```
+    fn clone_box<'a>(&self) -> Box<dyn Stack<T> + 'a>
+    where
+        Self: 'a;
+
+    /// Object-safe variant of [try_unary_fn_in_place](crate::stack::InPlaceFnApplication::try_unary_fn_in_place)
+    fn try_unary_fn_in_place_dyn(
+        &mut self,
+        unary_fn: &mut dyn FnMut(&mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError>;
+
+    /// Object-safe variant of [try_binary_fn_in_place_first_arg](crate::stack::InPlaceFnApplication::try_binary_fn_in_place_first_arg)
+    fn try_binary_fn_in_place_first_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError>;
+
+    /// Object-safe variant of [try_binary_fn_in_place_second_arg](crate::stack::InPlaceFnApplication::try_binary_fn_in_place_second_arg)
+    fn try_binary_fn_in_place_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&T, &mut T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError>;
+
+    /// Object-safe variant of [try_binary_fn_keeping_second_arg](crate::stack::InPlaceFnApplication::try_binary_fn_keeping_second_arg)
+    fn try_binary_fn_keeping_second_arg_dyn(
+        &mut self,
+        binary_fn: &mut dyn FnMut(&mut T, &T) -> Result<(), crate::StackError>,
+    ) -> Result<(), crate::StackError>;
+
+    /// Object-safe variant of [try_nary_fn_in_place](crate::stack::InPlaceFnApplication::try_nary_fn_in_place).
+    ///
+    /// The closure receives `num_inputs` operands and may return any number of results,
+    /// both ordered by level, index 0 being X.
+    fn try_nary_fn_in_place_dyn(
+        &mut self,
+        num_inputs: usize,
+        nary_fn: &mut dyn FnMut(Vec<T>) -> Result<Vec<T>, crate::StackError>,
+    ) -> Result<(), crate::StackError>;
+}
@@ -0,0 +1,65 @@
+use crate::stack::BasicStackOperations;
+
+/// RPL/Forth-style stack manipulation.
+///
+/// Levels are counted from zero like in [DynamicSizedStack::get](crate::stack::DynamicSizedStack::get),
+/// i.e. level 0 is X. RPL commands taking a level count from one, so `n PICK` in RPL
+/// corresponds to `pick(n - 1)`.
+pub trait ExtendedStackOperations: BasicStackOperations {
+    /// Duplicate X.
+    ///
+    /// ```text
+    /// a → a a
+    /// ```
+    fn dup(&mut self) -> Result<(), crate::StackError>;
+
+    /// Duplicate X and Y.
+    ///
+    /// ```text
+    /// b a → b a b a
+    /// ```
+    fn dup2(&mut self) -> Result<(), crate::StackError>;
+
+    /// Push a copy of Y.
+    ///
+    /// ```text
+    /// b a → b a b
+    /// ```
+    fn over(&mut self) -> Result<(), crate::StackError>;
+
+    /// Drop Y, keeping X.
+    ///
+    /// ```text
+    /// b a → a
+    /// ```
+    fn nip(&mut self) -> Result<(), crate::StackError>;
+
+    /// Insert a copy of X below Y.
+    ///
+    /// ```text
+    /// b a → a b a
+    /// ```
+    fn tuck(&mut self) -> Result<(), crate::StackError>;
+
+    /// Drop X and Y.
+    ///
+    /// ```text
+    /// b a →
+    /// ```
+    fn drop2(&mut self) -> Result<(), crate::StackError>;
+
+    /// Push a copy of level `idx`, `pick(0)` is the same as [dup](Self::dup).
+    fn pick(&mut self, idx: usize) -> Result<(), crate::StackError>;
+
+    /// Pop X and overwrite level `idx` of the remaining stack with it,
+    /// `unpick(0)` is the same as [nip](Self::nip).
+    fn unpick(&mut self, idx: usize) -> Result<(), crate::StackError>;
+
+    /// Move level `idx` to X, shifting the levels below it up.
+    /// `roll(1)` is the same as [swap](BasicStackOperations::swap).
+    fn roll(&mut self, idx: usize) -> Result<(), crate::StackError>;
+
+    /// Move X to level `idx`, shifting the levels below it down.
+    /// This is the inverse of [roll](Self::roll).
+    fn rolld(&mut self, idx: usize) -> Result<(), crate::StackError>;
+}
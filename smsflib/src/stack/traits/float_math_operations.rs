@@ -1,9 +1,36 @@
-use crate::stack::InPlaceFnApplication;
-use num_traits::Float;
+use crate::number::AngleMode;
+use crate::stack::{CalculatorState, InPlaceFnApplication};
+use num_traits::{Float, Zero};
 
-pub trait FloatMathOperations: InPlaceFnApplication
+/// Return a domain error if `result` is NaN although none of the `operands` is
+pub(super) fn checked_result<T: Float + std::fmt::Debug>(
+    operation: &'static str,
+    result: T,
+    operands: &[T],
+) -> Result<T, crate::StackError> {
+    if result.is_nan() && !operands.iter().any(|operand| operand.is_nan()) {
+        Err(crate::StackError::domain_error(operation, operands))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Real `n`-th root of `radicand`, NaN if there is none
+fn real_root<T: Float>(radicand: T, n: T) -> T {
+    let two = T::one() + T::one();
+    let is_odd_integer = n.fract().is_zero() && !(n / two).fract().is_zero();
+    if radicand.is_sign_negative() && is_odd_integer {
+        -(-radicand).powf(n.recip())
+    } else if n.is_zero() {
+        T::nan()
+    } else {
+        radicand.powf(n.recip())
+    }
+}
+
+pub trait FloatMathOperations: InPlaceFnApplication + CalculatorState
 where
-    <Self as InPlaceFnApplication>::Elem: num_traits::Float,
+    <Self as InPlaceFnApplication>::Elem: num_traits::Float + std::fmt::Debug,
 {
     /// # Example
     ///
@@ -19,11 +46,26 @@ where
     /// assert_eq!(*stack.z(), 3.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// Consumed operands are restored on error:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[-8.0, 0.5]);
+    /// let res = stack.pow();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("pow", &[0.5, -8.0])));
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&0.5));
+    /// assert_eq!(stack.get(1), Some(&-8.0));
+    /// ```
     fn pow(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_first_arg(
+        self.try_binary_fn_in_place_first_arg(
             |x: &mut <Self as InPlaceFnApplication>::Elem,
              y: &<Self as InPlaceFnApplication>::Elem| {
-                *x = y.powf(*x);
+                *x = checked_result("pow", y.powf(*x), &[*x, *y])?;
+                Ok(())
             },
         )
     }
@@ -42,9 +84,23 @@ where
     /// assert_eq!(*stack.z(), 2.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// A negative argument leaves the stack unchanged:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-1.0, 1.0, 2.0, 3.0);
+    /// let res = stack.ln();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("ln", &[-1.0])));
+    /// assert_eq!(*stack.x(), -1.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
     fn ln(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.ln();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("ln", x.ln(), &[*x])?;
+            Ok(())
         })
     }
 
@@ -63,8 +119,9 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn log2(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.log2();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("log2", x.log2(), &[*x])?;
+            Ok(())
         })
     }
 
@@ -83,8 +140,9 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn log10(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.log10();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("log10", x.log10(), &[*x])?;
+            Ok(())
         })
     }
 
@@ -103,8 +161,9 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn exp(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.exp();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("exp", x.exp(), &[*x])?;
+            Ok(())
         })
     }
 
@@ -123,8 +182,9 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn exp2(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.exp2();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("exp2", x.exp2(), &[*x])?;
+            Ok(())
         })
     }
 
@@ -142,9 +202,28 @@ where
     /// assert_eq!(*stack.z(), 2.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// In the other angle modes, the argument is reduced modulo a full turn in the unit of the
+    /// mode, and multiples of a quarter turn give exact results:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[180.0]);
+    /// stack.set_angle_mode(AngleMode::Degrees);
+    /// stack.sin();
+    /// assert_eq!(stack.get(0), Some(&0.0));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[100.0]);
+    /// stack.set_angle_mode(AngleMode::Gradians);
+    /// stack.sin();
+    /// assert_eq!(stack.get(0), Some(&1.0));
+    /// ```
     fn sin(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.sin();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("sin", mode.sin(*x), &[*x])?;
+            Ok(())
         })
     }
 
@@ -162,9 +241,25 @@ where
     /// assert_eq!(*stack.z(), 2.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[90.0]);
+    /// stack.set_angle_mode(AngleMode::Degrees);
+    /// stack.cos();
+    /// assert_eq!(stack.get(0), Some(&0.0));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[100.0]);
+    /// stack.set_angle_mode(AngleMode::Gradians);
+    /// stack.cos();
+    /// assert_eq!(stack.get(0), Some(&0.0));
+    /// ```
     fn cos(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.cos();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("cos", mode.cos(*x), &[*x])?;
+            Ok(())
         })
     }
 
@@ -182,9 +277,33 @@ where
     /// assert_eq!(*stack.z(), 2.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// An odd number of quarter turns is outside the domain:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[90.0]);
+    /// stack.set_angle_mode(AngleMode::Degrees);
+    /// let res = stack.tan();
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("tan", &[90.0])));
+    /// assert_eq!(stack.get(0), Some(&90.0));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[100.0]);
+    /// stack.set_angle_mode(AngleMode::Gradians);
+    /// let res = stack.tan();
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("tan", &[100.0])));
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[180.0]);
+    /// stack.set_angle_mode(AngleMode::Degrees);
+    /// stack.tan();
+    /// assert_eq!(stack.get(0), Some(&0.0));
+    /// ```
     fn tan(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.tan();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("tan", mode.tan(*x), &[*x])?;
+            Ok(())
         })
     }
 
@@ -202,9 +321,22 @@ where
     /// assert_eq!(*stack.z(), 2.0);
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[1.0, 2.0]);
+    /// let res = stack.asin();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("asin", &[2.0])));
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&2.0));
+    /// ```
     fn asin(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.asin();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("asin", mode.from_radians(x.asin()), &[*x])?;
+            Ok(())
         })
     }
 
@@ -223,8 +355,10 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn acos(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.acos();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("acos", mode.from_radians(x.acos()), &[*x])?;
+            Ok(())
         })
     }
 
@@ -243,8 +377,10 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn atan(&mut self) -> Result<(), crate::StackError> {
-        self.unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
-            *x = x.atan();
+        let mode = self.angle_mode();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("atan", mode.from_radians(x.atan()), &[*x])?;
+            Ok(())
         })
     }
 
@@ -263,11 +399,540 @@ where
     /// assert_eq!(*stack.t(), 3.0);
     /// ```
     fn atan2(&mut self) -> Result<(), crate::StackError> {
-        self.binary_fn_in_place_first_arg(
+        let mode = self.angle_mode();
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_result("atan2", mode.from_radians(y.atan2(*x)), &[*x, *y])?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Convert X from degrees to radians, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(90.0, 1.0, 2.0, 3.0);
+    /// stack.deg_to_rad();
+    ///
+    /// assert_approx_eq!(*stack.x(), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn deg_to_rad(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Degrees.convert(*x, AngleMode::Radians);
+            Ok(())
+        })
+    }
+
+    /// Convert X from radians to degrees, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(std::f64::consts::PI, 1.0, 2.0, 3.0);
+    /// stack.rad_to_deg();
+    ///
+    /// assert_approx_eq!(*stack.x(), 180.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn rad_to_deg(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Radians.convert(*x, AngleMode::Degrees);
+            Ok(())
+        })
+    }
+
+    /// Convert X from gradians to radians, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(50.0, 1.0, 2.0, 3.0);
+    /// stack.grad_to_rad();
+    ///
+    /// assert_approx_eq!(*stack.x(), std::f64::consts::FRAC_PI_4);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn grad_to_rad(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Gradians.convert(*x, AngleMode::Radians);
+            Ok(())
+        })
+    }
+
+    /// Convert X from radians to gradians, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(std::f64::consts::FRAC_PI_4, 1.0, 2.0, 3.0);
+    /// stack.rad_to_grad();
+    ///
+    /// assert_approx_eq!(*stack.x(), 50.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn rad_to_grad(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Radians.convert(*x, AngleMode::Gradians);
+            Ok(())
+        })
+    }
+
+    /// Convert X from degrees to gradians, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(45.0, 1.0, 2.0, 3.0);
+    /// stack.deg_to_grad();
+    ///
+    /// assert_approx_eq!(*stack.x(), 50.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn deg_to_grad(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Degrees.convert(*x, AngleMode::Gradians);
+            Ok(())
+        })
+    }
+
+    /// Convert X from gradians to degrees, independently of the angle mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(50.0, 1.0, 2.0, 3.0);
+    /// stack.grad_to_deg();
+    ///
+    /// assert_approx_eq!(*stack.x(), 45.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn grad_to_deg(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = AngleMode::Gradians.convert(*x, AngleMode::Degrees);
+            Ok(())
+        })
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.0, 1.0, 2.0, 3.0);
+    /// stack.sqrt();
+    ///
+    /// assert_approx_eq!(*stack.x(), std::f64::consts::SQRT_2);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-4.0, 1.0, 2.0, 3.0);
+    /// let res = stack.sqrt();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("sqrt", &[-4.0])));
+    /// assert_eq!(*stack.x(), -4.0);
+    /// ```
+    fn sqrt(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("sqrt", x.sqrt(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-3.0, 1.0, 2.0, 3.0);
+    /// stack.square();
+    ///
+    /// assert_eq!(*stack.x(), 9.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    fn square(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("square", *x * *x, &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(4.0, 1.0, 2.0, 3.0);
+    /// stack.reciprocal();
+    ///
+    /// assert_eq!(*stack.x(), 0.25);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.0, 1.0, 2.0, 3.0);
+    /// let res = stack.reciprocal();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("reciprocal", &[0.0])));
+    /// assert_eq!(*stack.x(), 0.0);
+    /// ```
+    fn reciprocal(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            if x.is_zero() {
+                return Err(crate::StackError::division_by_zero("reciprocal", &[*x]));
+            }
+            *x = x.recip();
+            Ok(())
+        })
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-27.0, 1.0, 2.0, 3.0);
+    /// stack.cbrt();
+    ///
+    /// assert_approx_eq!(*stack.x(), -3.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn cbrt(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("cbrt", x.cbrt(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// X-th root of Y
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(4.0, 81.0, 2.0, 3.0);
+    /// stack.nth_root();
+    ///
+    /// assert_approx_eq!(*stack.x(), 3.0);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// assert_eq!(*stack.z(), 3.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    ///
+    /// Odd roots of negative numbers are real:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(5.0, -32.0, 2.0, 3.0);
+    /// stack.nth_root();
+    ///
+    /// assert_approx_eq!(*stack.x(), -2.0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.0, -4.0, 2.0, 3.0);
+    /// let res = stack.nth_root();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("nth_root", &[2.0, -4.0])));
+    /// assert_eq!(*stack.x(), 2.0);
+    /// ```
+    fn nth_root(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
             |x: &mut <Self as InPlaceFnApplication>::Elem,
              y: &<Self as InPlaceFnApplication>::Elem| {
-                *x = y.atan2(*x);
+                *x = checked_result("nth_root", real_root(*y, *x), &[*x, *y])?;
+                Ok(())
             },
         )
     }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(3.0, 4.0, 2.0, 3.0);
+    /// stack.hypot();
+    ///
+    /// assert_approx_eq!(*stack.x(), 5.0);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// assert_eq!(*stack.z(), 3.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    fn hypot(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_result("hypot", x.hypot(*y), &[*x, *y])?;
+                Ok(())
+            },
+        )
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.5, 1.0, 2.0, 3.0);
+    /// stack.floor();
+    /// assert_eq!(*stack.x(), -3.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.floor();
+    /// assert_eq!(*stack.x(), 2.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn floor(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("floor", x.floor(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.5, 1.0, 2.0, 3.0);
+    /// stack.ceil();
+    /// assert_eq!(*stack.x(), -2.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.ceil();
+    /// assert_eq!(*stack.x(), 3.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn ceil(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("ceil", x.ceil(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Round X to the nearest integer, halfway cases away from zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.5, 1.0, 2.0, 3.0);
+    /// stack.round();
+    /// assert_eq!(*stack.x(), -3.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.round();
+    /// assert_eq!(*stack.x(), 3.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn round(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("round", x.round(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Integer part of X, i.e. X rounded towards zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.5, 1.0, 2.0, 3.0);
+    /// stack.trunc();
+    /// assert_eq!(*stack.x(), -2.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.trunc();
+    /// assert_eq!(*stack.x(), 2.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn trunc(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("trunc", x.trunc(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Fractional part of X, having the sign of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.75, 1.0, 2.0, 3.0);
+    /// stack.fract();
+    ///
+    /// assert_approx_eq!(*stack.x(), -0.75);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn fract(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("fract", x.fract(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Sign of X: -1, 0 or 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.5, 1.0, 2.0, 3.0);
+    /// stack.sign();
+    /// assert_eq!(*stack.x(), -1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.0, 1.0, 2.0, 3.0);
+    /// stack.sign();
+    /// assert_eq!(*stack.x(), 0.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn sign(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            if !x.is_zero() {
+                *x = x.signum();
+            }
+            Ok(())
+        })
+    }
+
+    /// Smaller of X and Y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(3.0, -4.0, 2.0, 3.0);
+    /// stack.min();
+    ///
+    /// assert_eq!(*stack.x(), -4.0);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// ```
+    fn min(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_result("min", x.min(*y), &[*x, *y])?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Larger of X and Y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(3.0, -4.0, 2.0, 3.0);
+    /// stack.max();
+    ///
+    /// assert_eq!(*stack.x(), 3.0);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// ```
+    fn max(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_result("max", x.max(*y), &[*x, *y])?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Magnitude of Y with the sign of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-0.0, 2.5, 2.0, 3.0);
+    /// stack.copysign();
+    ///
+    /// assert_eq!(*stack.x(), -2.5);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// ```
+    fn copysign(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = if x.is_sign_negative() == y.is_sign_negative() {
+                    *y
+                } else {
+                    -*y
+                };
+                Ok(())
+            },
+        )
+    }
+
+    /// Fused multiply-add Y · X + Z with a single rounding
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.1, 10.0, -1.0, 3.0);
+    /// stack.fma();
+    ///
+    /// assert_eq!(*stack.x(), 0.1_f64.mul_add(10.0, -1.0));
+    /// assert_eq!(*stack.y(), 3.0);
+    /// assert_eq!(*stack.z(), 3.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    fn fma(&mut self) -> Result<(), crate::StackError> {
+        self.try_nary_fn_in_place(|[x, y, z]: [<Self as InPlaceFnApplication>::Elem; 3]| {
+            Ok([checked_result("fma", y.mul_add(x, z), &[x, y, z])?])
+        })
+    }
 }
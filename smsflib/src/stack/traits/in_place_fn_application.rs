@@ -15,4 +15,139 @@ pub trait InPlaceFnApplication {
         &mut self,
         binary_fn: U,
     ) -> Result<(), crate::StackError>;
+
+    /// Fallible variant of [unary_fn_in_place](InPlaceFnApplication::unary_fn_in_place).
+    /// If the closure returns [Err], the stack is left unchanged.
+    fn try_unary_fn_in_place<U: FnOnce(&mut Self::Elem) -> Result<(), crate::StackError>>(
+        &mut self,
+        unary_fn: U,
+    ) -> Result<(), crate::StackError>;
+
+    /// Fallible variant of [binary_fn_in_place_first_arg](InPlaceFnApplication::binary_fn_in_place_first_arg).
+    /// If the closure returns [Err], the stack is left unchanged.
+    fn try_binary_fn_in_place_first_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError>;
+
+    /// Fallible variant of [binary_fn_in_place_second_arg](InPlaceFnApplication::binary_fn_in_place_second_arg).
+    /// If the closure returns [Err], the stack is left unchanged.
+    fn try_binary_fn_in_place_second_arg<
+        U: FnOnce(&Self::Elem, &mut Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError>;
+
+    /// Apply a fallible binary operation to X and Y, replacing X with the result and keeping Y,
+    /// like the percentage keys of financial calculators.
+    /// If the closure returns [Err], the stack is left unchanged.
+    fn try_binary_fn_keeping_second_arg<
+        U: FnOnce(&mut Self::Elem, &Self::Elem) -> Result<(), crate::StackError>,
+    >(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError>;
+
+    /// Apply a fallible operation consuming `K` registers and producing `M` registers.
+    ///
+    /// Both the operands and the results are ordered by level, index 0 being X.
+    /// If the closure returns [Err], the stack is left unchanged.
+    fn try_nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([Self::Elem; K]) -> Result<[Self::Elem; M], crate::StackError>,
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError>;
+
+    /// Apply a binary operation to X and Y, replacing X with the result and keeping Y.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(3, 10, 20, 30);
+    /// let res = stack.binary_fn_keeping_second_arg(|x: &mut i32, y: &i32| *x *= y);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 30);
+    /// assert_eq!(*stack.y(), 10);
+    /// assert_eq!(*stack.z(), 20);
+    /// assert_eq!(*stack.t(), 30);
+    /// assert_eq!(stack.last_x_value(), Some(&3));
+    /// ```
+    fn binary_fn_keeping_second_arg<U: FnOnce(&mut Self::Elem, &Self::Elem)>(
+        &mut self,
+        binary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.try_binary_fn_keeping_second_arg(|x, y| {
+            binary_fn(x, y);
+            Ok(())
+        })
+    }
+
+    /// Apply an operation consuming `K` registers and producing `M` registers.
+    ///
+    /// Both the operands and the results are ordered by level, index 0 being X.
+    ///
+    /// # Examples
+    ///
+    /// Polar to rectangular conversion with the radius in X and the angle in Y:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.0, std::f64::consts::FRAC_PI_6, 3.0, 4.0);
+    /// let res = stack.nary_fn_in_place(|[r, phi]: [f64; 2]| [r * phi.cos(), r * phi.sin()]);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_approx_eq!(*stack.x(), 3.0_f64.sqrt());
+    /// assert_approx_eq!(*stack.y(), 1.0);
+    /// assert_eq!(*stack.z(), 3.0);
+    /// assert_eq!(*stack.t(), 4.0);
+    /// ```
+    ///
+    /// Fused multiply-add of X and Y plus Z:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[7.0, 1.0, 3.0, 2.0]);
+    /// let res = stack.nary_fn_in_place(|[x, y, z]: [f64; 3]| [x.mul_add(y, z)]);
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack.get(0), Some(&7.0));
+    /// assert_eq!(stack.get(1), Some(&7.0));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[1.0, 2.0]);
+    /// let res = stack.nary_fn_in_place(|[x, y, z]: [f64; 3]| [x.mul_add(y, z)]);
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 3, num_available: 2}));
+    ///
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    fn nary_fn_in_place<
+        const K: usize,
+        const M: usize,
+        U: FnOnce([Self::Elem; K]) -> [Self::Elem; M],
+    >(
+        &mut self,
+        nary_fn: U,
+    ) -> Result<(), crate::StackError> {
+        self.try_nary_fn_in_place(|operands| Ok(nary_fn(operands)))
+    }
 }
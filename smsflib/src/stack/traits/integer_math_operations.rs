@@ -0,0 +1,425 @@
+use crate::stack::InPlaceFnApplication;
+use num_traits::{CheckedDiv, CheckedMul, PrimInt, ToPrimitive, Zero};
+
+/// Check whether `x` is -1 without computing -1, which does not exist for unsigned types
+fn is_minus_one<T: PrimInt>(x: T) -> bool {
+    x.checked_add(&T::one()) == Some(T::zero())
+}
+
+/// Truncated remainder of `y / x` for a non-zero `x`, which unlike `%` does not overflow
+/// for `T::min_value() % -1`
+fn truncated_rem<T: PrimInt>(y: T, x: T) -> T {
+    if is_minus_one(x) {
+        T::zero()
+    } else {
+        y % x
+    }
+}
+
+/// Remainder of the floored division of `y` by a non-zero `x`, having the sign of `x`
+fn floored_rem<T: PrimInt>(y: T, x: T) -> T {
+    let r = truncated_rem(y, x);
+    if !r.is_zero() && ((r < T::zero()) != (x < T::zero())) {
+        r + x
+    } else {
+        r
+    }
+}
+
+/// Absolute value, [None] if it does not fit into `T`
+fn checked_abs<T: PrimInt>(x: T) -> Option<T> {
+    if x < T::zero() {
+        T::zero().checked_sub(&x)
+    } else {
+        Some(x)
+    }
+}
+
+/// Non-negative greatest common divisor, [None] if it does not fit into `T`
+fn checked_gcd<T: PrimInt>(mut a: T, mut b: T) -> Option<T> {
+    while !b.is_zero() {
+        let r = truncated_rem(a, b);
+        a = b;
+        b = r;
+    }
+    checked_abs(a)
+}
+
+/// Integer square root of a non-negative `x`, computed digit by digit
+fn isqrt<T: PrimInt>(x: T) -> T {
+    let num_bits = T::zero().count_zeros() as usize;
+    let mut remainder = x;
+    let mut root = T::zero();
+    let mut bit = T::one() << (num_bits - 2);
+    while bit > remainder {
+        bit = bit >> 2;
+    }
+    while !bit.is_zero() {
+        if remainder >= root + bit {
+            remainder = remainder - (root + bit);
+            root = (root >> 1) + bit;
+        } else {
+            root = root >> 1;
+        }
+        bit = bit >> 2;
+    }
+    root
+}
+
+/// Binomial coefficient for `0 <= k <= n`, [None] if it does not fit into `T`
+fn checked_binomial<T: PrimInt>(n: T, k: T) -> Option<T> {
+    let k = k.min(n - k);
+    let mut result = T::one();
+    let mut i = T::one();
+    while i <= k {
+        // 'result * (n - k + i)' is divisible by 'i', cancel the common factor first
+        // so that the intermediate product only overflows if the result does
+        let g = checked_gcd(result, i)?;
+        result = (result / g).checked_mul(&((n - k + i) / (i / g)))?;
+        i = i + T::one();
+    }
+    Some(result)
+}
+
+/// Integer arithmetic beyond the [BasicMathOperations](crate::stack::BasicMathOperations).
+///
+/// Results which do not fit into the element type are always reported as
+/// [Overflow](crate::StackError::Overflow) errors, regardless of the arithmetic policy.
+pub trait IntegerMathOperations: InPlaceFnApplication
+where
+    <Self as InPlaceFnApplication>::Elem: PrimInt + std::fmt::Debug,
+{
+    /// Y modulo X, the result has the sign of X like `MOD` on HP calculators
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(5, -7, 0, 1);
+    /// let res = stack.modulo();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 3);
+    /// assert_eq!(*stack.y(), 0);
+    /// assert_eq!(*stack.z(), 1);
+    /// assert_eq!(*stack.t(), 1);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u32>::clone_from_slice(&[7, 0]);
+    /// let res = stack.modulo();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("modulo", &[0, 7])));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    fn modulo(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if x.is_zero() {
+                    return Err(crate::StackError::division_by_zero("modulo", &[*x, *y]));
+                }
+                *y = floored_rem(*y, *x);
+                Ok(())
+            },
+        )
+    }
+
+    /// Euclidean remainder of Y divided by X, which is never negative
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(-5, -7, 0, 1);
+    /// let res = stack.rem_euclid();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 3);
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(-1, i32::MIN, 0, 1);
+    /// let res = stack.rem_euclid();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0);
+    /// ```
+    fn rem_euclid(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if x.is_zero() {
+                    return Err(crate::StackError::division_by_zero("rem_euclid", &[*x, *y]));
+                }
+                let r = truncated_rem(*y, *x);
+                *y = if r >= num_traits::zero() {
+                    r
+                } else if *x < num_traits::zero() {
+                    r - *x
+                } else {
+                    r + *x
+                };
+                Ok(())
+            },
+        )
+    }
+
+    /// Y divided by X, rounded towards negative infinity so that
+    /// Y = X · [integer_divide](IntegerMathOperations::integer_divide) + [modulo](IntegerMathOperations::modulo)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(5, -7, 0, 1);
+    /// let res = stack.integer_divide();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), -2);
+    /// assert_eq!(*stack.y(), 0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i8>::new(-1, -128, 0, 1);
+    /// let res = stack.integer_divide();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("integer_divide", &[-1, -128])));
+    /// assert_eq!(*stack.x(), -1);
+    /// ```
+    fn integer_divide(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if x.is_zero() {
+                    return Err(crate::StackError::division_by_zero(
+                        "integer_divide",
+                        &[*x, *y],
+                    ));
+                }
+                let q = y
+                    .checked_div(x)
+                    .ok_or_else(|| crate::StackError::overflow("integer_divide", &[*x, *y]))?;
+                let r = truncated_rem(*y, *x);
+                *y = if !r.is_zero() && ((r < num_traits::zero()) != (*x < num_traits::zero())) {
+                    q - num_traits::one()
+                } else {
+                    q
+                };
+                Ok(())
+            },
+        )
+    }
+
+    /// Greatest common divisor of X and Y, which is never negative
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i64>::new(-84, 36, 0, 1);
+    /// let res = stack.gcd();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 12);
+    /// assert_eq!(*stack.y(), 0);
+    /// ```
+    fn gcd(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_gcd(*x, *y)
+                    .ok_or_else(|| crate::StackError::overflow("gcd", &[*x, *y]))?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Least common multiple of X and Y, which is never negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u32>::new(4, 6, 0, 1);
+    /// let res = stack.lcm();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 12);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u8>::new(16, 17, 0, 1);
+    /// let res = stack.lcm();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("lcm", &[16, 17])));
+    /// ```
+    fn lcm(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                if x.is_zero() || y.is_zero() {
+                    *x = num_traits::zero();
+                    return Ok(());
+                }
+                *x = checked_gcd(*x, *y)
+                    .and_then(|g| (*x / g).checked_mul(y))
+                    .and_then(checked_abs)
+                    .ok_or_else(|| crate::StackError::overflow("lcm", &[*x, *y]))?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Y raised to the non-negative integer power X
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(3, -2, 0, 1);
+    /// let res = stack.integer_pow();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), -8);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(-1, 2, 0, 1);
+    /// let res = stack.integer_pow();
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("integer_pow", &[-1, 2])));
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(31, 2, 0, 1);
+    /// let res = stack.integer_pow();
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("integer_pow", &[31, 2])));
+    /// ```
+    fn integer_pow(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if *x < num_traits::zero() {
+                    return Err(crate::StackError::domain_error("integer_pow", &[*x, *y]));
+                }
+                *y = x
+                    .to_usize()
+                    .and_then(|exp| num_traits::checked_pow(*y, exp))
+                    .ok_or_else(|| crate::StackError::overflow("integer_pow", &[*x, *y]))?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Integer square root of X, i.e. the largest integer whose square does not exceed X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u64>::new(99, 2, 3, 4);
+    /// let res = stack.isqrt();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 9);
+    /// assert_eq!(*stack.y(), 2);
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(-4, 2, 3, 4);
+    /// let res = stack.isqrt();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("isqrt", &[-4])));
+    /// ```
+    fn isqrt(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            if *x < num_traits::zero() {
+                return Err(crate::StackError::domain_error("isqrt", &[*x]));
+            }
+            *x = isqrt(*x);
+            Ok(())
+        })
+    }
+
+    /// Factorial of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u64>::new(20, 2, 3, 4);
+    /// let res = stack.factorial();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 2_432_902_008_176_640_000);
+    ///
+    /// let mut stack = ClassicStack::<u64>::new(21, 2, 3, 4);
+    /// let res = stack.factorial();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("factorial", &[21])));
+    /// assert_eq!(*stack.x(), 21);
+    /// ```
+    fn factorial(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            if *x < num_traits::zero() {
+                return Err(crate::StackError::domain_error("factorial", &[*x]));
+            }
+            let mut result: <Self as InPlaceFnApplication>::Elem = num_traits::one();
+            let mut i = result;
+            while i < *x {
+                i = i + num_traits::one();
+                result = result
+                    .checked_mul(&i)
+                    .ok_or_else(|| crate::StackError::overflow("factorial", &[*x]))?;
+            }
+            *x = result;
+            Ok(())
+        })
+    }
+
+    /// Binomial coefficient "Y choose X", zero if X exceeds Y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<u64>::new(30, 60, 0, 1);
+    /// let res = stack.binomial();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 118_264_581_564_861_424);
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(2, -5, 0, 1);
+    /// let res = stack.binomial();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("binomial", &[2, -5])));
+    /// ```
+    fn binomial(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_second_arg(
+            |x: &<Self as InPlaceFnApplication>::Elem,
+             y: &mut <Self as InPlaceFnApplication>::Elem| {
+                if *x < num_traits::zero() || *y < num_traits::zero() {
+                    return Err(crate::StackError::domain_error("binomial", &[*x, *y]));
+                }
+                *y = if x > y {
+                    num_traits::zero()
+                } else {
+                    checked_binomial(*y, *x)
+                        .ok_or_else(|| crate::StackError::overflow("binomial", &[*x, *y]))?
+                };
+                Ok(())
+            },
+        )
+    }
+}
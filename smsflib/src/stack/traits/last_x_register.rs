@@ -0,0 +1,17 @@
+use crate::stack::BasicStackOperations;
+
+/// HP-style LastX register.
+///
+/// Operations applied through [InPlaceFnApplication](crate::stack::InPlaceFnApplication)
+/// save the X value they consumed, so that it can be recalled after a wrong operator.
+/// Failed operations leave the register unchanged.
+pub trait LastXRegister: BasicStackOperations {
+    /// Get the X value consumed by the last successful operation, if any
+    fn last_x_value(&self) -> Option<&Self::Elem>;
+
+    /// Push the value of the LastX register onto the stack.
+    ///
+    /// Return [EmptyLastX](crate::StackError::EmptyLastX) and leave the stack unchanged
+    /// if no operation has saved a value yet.
+    fn last_x(&mut self) -> Result<(), crate::StackError>;
+}
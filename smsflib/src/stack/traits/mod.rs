@@ -1,9 +1,29 @@
 mod basic_math_operations;
 mod basic_stack_operations;
+mod calculator_state;
+mod depth_operations;
+mod dyn_stack;
+mod extended_stack_operations;
 mod float_math_operations;
 mod in_place_fn_application;
+mod integer_math_operations;
+mod last_x_register;
+mod percentage_operations;
+mod signed_math_operations;
+mod special_functions;
+mod transaction;
 
 pub use basic_math_operations::BasicMathOperations;
 pub use basic_stack_operations::BasicStackOperations;
+pub use calculator_state::CalculatorState;
+pub use depth_operations::DepthOperations;
+pub use dyn_stack::Stack;
+pub use extended_stack_operations::ExtendedStackOperations;
 pub use float_math_operations::FloatMathOperations;
 pub use in_place_fn_application::InPlaceFnApplication;
+pub use integer_math_operations::IntegerMathOperations;
+pub use last_x_register::LastXRegister;
+pub use percentage_operations::PercentageOperations;
+pub use signed_math_operations::SignedMathOperations;
+pub use special_functions::SpecialFunctions;
+pub use transaction::Transaction;
@@ -0,0 +1,309 @@
+use crate::number::ArithmeticPolicy;
+use crate::number::OverflowArithmetic;
+use crate::stack::{BasicMathOperations, InPlaceFnApplication};
+use num_traits::Zero;
+
+/// Percentages like the %, Δ% and %T keys of the HP-12C.
+///
+/// The base stays in Y, so that several percentages of the same base can be calculated
+/// one after the other. X is replaced with the result and saved in the LastX register.
+///
+/// # Example
+///
+/// A price of 80 with 15 % discount:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// let mut stack = ClassicStack::<f64>::new(15.0, 80.0, 0.0, 0.0);
+/// stack.percent();
+/// assert_eq!(*stack.x(), 12.0);
+/// assert_eq!(*stack.y(), 80.0);
+///
+/// stack.swap();
+/// stack.subtract();
+/// assert_eq!(*stack.x(), 68.0);
+/// ```
+pub trait PercentageOperations: BasicMathOperations
+where
+    <Self as InPlaceFnApplication>::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::FromPrimitive
+        + OverflowArithmetic
+        + PartialOrd
+        + std::fmt::Debug,
+{
+    /// X percent of Y (%)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(14.0, 300.0, 2.0, 3.0);
+    /// stack.percent();
+    ///
+    /// assert_eq!(*stack.x(), 42.0);
+    /// assert_eq!(*stack.y(), 300.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// assert_eq!(stack.last_x_value(), Some(&14.0));
+    /// ```
+    ///
+    /// Integer results are truncated. Only results which do not fit into the element type
+    /// follow the arithmetic policy, intermediate products do not overflow:
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// for policy in [ArithmeticPolicy::Checked, ArithmeticPolicy::Wrapping, ArithmeticPolicy::Saturating] {
+    ///     let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[200, 15]);
+    ///     stack.set_arithmetic_policy(policy);
+    ///
+    ///     assert_eq!(stack.percent(), Ok(()));
+    ///     assert_eq!(stack.get(0), Some(&30));
+    ///     assert_eq!(stack.get(1), Some(&200));
+    /// }
+    ///
+    /// let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[200, 150]);
+    /// let res = stack.percent();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::overflow("percent", &[150, 200])));
+    /// assert_eq!(stack.get(0), Some(&150));
+    ///
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    /// stack.percent();
+    /// assert_eq!(stack.get(0), Some(&44));
+    ///
+    /// let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[200, 150]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Saturating);
+    /// stack.percent();
+    /// assert_eq!(stack.get(0), Some(&255));
+    /// ```
+    fn percent(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_keeping_second_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = mul_div(y, x, &hundred(), policy)
+                    .ok_or_else(|| crate::StackError::overflow("percent", &[&*x, y]))?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Change from Y to X in percent of Y (Δ%)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(58.5, 52.0, 2.0, 3.0);
+    /// stack.percent_change();
+    ///
+    /// assert_eq!(*stack.x(), 12.5);
+    /// assert_eq!(*stack.y(), 52.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(10.0, 0.0, 2.0, 3.0);
+    /// let res = stack.percent_change();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("percent_change", &[10.0, 0.0])));
+    /// assert_eq!(*stack.x(), 10.0);
+    /// ```
+    ///
+    /// Integer results follow the arithmetic policy like those of [percent](Self::percent):
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[200, 250]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    /// stack.percent_change();
+    /// assert_eq!(stack.get(0), Some(&25));
+    ///
+    /// let mut stack = DynamicSizedStack::<i8>::clone_from_slice(&[-50, 50]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    /// stack.percent_change();
+    /// assert_eq!(stack.get(0), Some(&56));
+    ///
+    /// let mut stack = DynamicSizedStack::<i8>::clone_from_slice(&[-50, 50]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Saturating);
+    /// stack.percent_change();
+    /// assert_eq!(stack.get(0), Some(&-128));
+    /// ```
+    fn percent_change(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_keeping_second_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                if y.is_zero() {
+                    return Err(crate::StackError::division_by_zero(
+                        "percent_change",
+                        &[&*x, y],
+                    ));
+                }
+                *x = x
+                    .sub_with(y, policy)
+                    .and_then(|change| mul_div(&change, &hundred(), y, policy))
+                    .ok_or_else(|| crate::StackError::overflow("percent_change", &[&*x, y]))?;
+                Ok(())
+            },
+        )
+    }
+
+    /// X in percent of the total Y (%T)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(3.0, 12.0, 2.0, 3.0);
+    /// stack.percent_of_total();
+    ///
+    /// assert_eq!(*stack.x(), 25.0);
+    /// assert_eq!(*stack.y(), 12.0);
+    /// assert_eq!(*stack.z(), 2.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[0, 3]);
+    /// let res = stack.percent_of_total();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::division_by_zero("percent_of_total", &[3, 0])));
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[40_000_000, 30_000_000]);
+    /// stack.percent_of_total();
+    /// assert_eq!(stack.get(0), Some(&75));
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[50, 200]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Wrapping);
+    /// stack.percent_of_total();
+    /// assert_eq!(stack.get(0), Some(&144));
+    ///
+    /// let mut stack = DynamicSizedStack::<u8>::clone_from_slice(&[50, 200]);
+    /// stack.set_arithmetic_policy(ArithmeticPolicy::Saturating);
+    /// stack.percent_of_total();
+    /// assert_eq!(stack.get(0), Some(&255));
+    /// ```
+    fn percent_of_total(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_binary_fn_keeping_second_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                if y.is_zero() {
+                    return Err(crate::StackError::division_by_zero(
+                        "percent_of_total",
+                        &[&*x, y],
+                    ));
+                }
+                *x = mul_div(x, &hundred(), y, policy)
+                    .ok_or_else(|| crate::StackError::overflow("percent_of_total", &[&*x, y]))?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// The number 100 in the element type
+fn hundred<T: num_traits::FromPrimitive>() -> T {
+    T::from_u8(100).expect("100 fits into every numeric type")
+}
+
+/// `a · b / c` truncated towards zero, with the policy applied to the result only.
+///
+/// If the product overflows, integers are split into `a = q · c + r` and the result is
+/// calculated as `q · b + r · b / c`, where the second term is exact and smaller than `b`.
+fn mul_div<T>(a: &T, b: &T, c: &T, policy: ArithmeticPolicy) -> Option<T>
+where
+    T: Clone + num_traits::NumAssignRef + OverflowArithmetic + PartialOrd,
+{
+    if let Some(product) = a.mul_with(b, ArithmeticPolicy::Checked) {
+        return product.div_with(c, policy);
+    }
+    let is_integer = |value: &T| (value.clone() % T::one()).is_zero();
+    if !(is_integer(a) && is_integer(b) && is_integer(c)) {
+        return a.mul_with(b, policy)?.div_with(c, policy);
+    }
+    if c.is_one() {
+        return a.mul_with(b, policy);
+    }
+    if *c < T::zero() && (c.clone() + T::one()).is_zero() {
+        // a · b / -1 = a · -b, but -b overflows for the minimum, a · (-1 - b) + a does not
+        return if *b < T::zero() {
+            let decremented = T::zero() - (b.clone() + T::one());
+            a.mul_with(&decremented, policy)?.add_with(a, policy)
+        } else {
+            a.mul_with(&(T::zero() - b.clone()), policy)
+        };
+    }
+    let remainder = a.clone() % c.clone();
+    let quotient = (a.clone() - remainder.clone()) / c.clone();
+    let (tail, _) = mul_rem(&remainder, b, c);
+    quotient.mul_with(b, policy)?.add_with(&tail, policy)
+}
+
+/// Quotient and remainder of `r · v / c` for integers with `|r| < |c|`, truncated towards zero.
+///
+/// Binary long division over the digits of `v`, so that no intermediate result is larger than
+/// the quotient or `c`.
+fn mul_rem<T>(r: &T, v: &T, c: &T) -> (T, T)
+where
+    T: Clone + num_traits::NumAssignRef + PartialOrd,
+{
+    if v.is_zero() {
+        return (T::zero(), T::zero());
+    }
+    let two = T::one() + T::one();
+    let half = (v.clone() - v.clone() % two.clone()) / two.clone();
+    let bit = v.clone() - half.clone() * two;
+    let (quotient, remainder) = mul_rem(r, &half, c);
+    // r · v = 2 · quotient · c + remainder + remainder + bit · r, all terms with the same sign
+    let (first_carry, remainder) = add_rem(&remainder, &remainder, c);
+    let (second_carry, remainder) = add_rem(&remainder, &(bit * r.clone()), c);
+    (
+        quotient.clone() + quotient + first_carry + second_carry,
+        remainder,
+    )
+}
+
+/// Quotient and remainder of `(u + w) / c` for integers of the same sign with `|u|, |w| < |c|`
+fn add_rem<T>(u: &T, w: &T, c: &T) -> (T, T)
+where
+    T: Clone + num_traits::NumAssignRef + PartialOrd,
+{
+    let negative = |value: &T| *value < T::zero();
+    // Flip into the sign of c, which cannot overflow as both are smaller than |c|
+    let towards_c = |value: &T| {
+        if negative(value) == negative(c) {
+            value.clone()
+        } else {
+            T::zero() - value.clone()
+        }
+    };
+    let (u_c, gap) = (towards_c(u), c.clone() - towards_c(w));
+    let reaches_c = if negative(c) { u_c <= gap } else { u_c >= gap };
+    if !reaches_c {
+        return (T::zero(), u.clone() + w.clone());
+    }
+    let excess = u_c - gap;
+    if (negative(u) || negative(w)) == negative(c) {
+        (T::one(), excess)
+    } else {
+        (T::zero() - T::one(), T::zero() - excess)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::number::OverflowArithmetic;
+use crate::stack::{BasicMathOperations, InPlaceFnApplication};
+
+/// Arithmetic which only makes sense for signed element types
+pub trait SignedMathOperations: BasicMathOperations
+where
+    <Self as InPlaceFnApplication>::Elem: Clone
+        + num_traits::NumAssignRef
+        + num_traits::Signed
+        + OverflowArithmetic
+        + std::fmt::Debug,
+{
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(1, 2, 3, 4);
+    /// stack.change_sign();
+    ///
+    /// assert_eq!(*stack.x(), -1);
+    /// assert_eq!(*stack.y(), 2);
+    /// assert_eq!(*stack.z(), 3);
+    /// assert_eq!(*stack.t(), 4);
+    /// ```
+    fn change_sign(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = x
+                .neg_with(policy)
+                .ok_or_else(|| crate::StackError::overflow("change_sign", &[&*x]))?;
+            Ok(())
+        })
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<i32>::new(-1, -2, -3, -4);
+    /// stack.absolute_value();
+    ///
+    /// assert_eq!(*stack.x(), 1);
+    /// assert_eq!(*stack.y(), -2);
+    /// assert_eq!(*stack.z(), -3);
+    /// assert_eq!(*stack.t(), -4);
+    /// ```
+    fn absolute_value(&mut self) -> Result<(), crate::StackError> {
+        let policy = self.arithmetic_policy();
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = x
+                .abs_with(policy)
+                .ok_or_else(|| crate::StackError::overflow("absolute_value", &[&*x]))?;
+            Ok(())
+        })
+    }
+}
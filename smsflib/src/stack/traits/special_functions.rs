@@ -0,0 +1,539 @@
+use super::float_math_operations::checked_result;
+use crate::number::special_functions;
+use crate::stack::InPlaceFnApplication;
+use num_traits::Float;
+
+/// Hyperbolic functions and the special functions of engineering and statistics.
+///
+/// # Accuracy
+///
+/// The targets below hold for `f64` elements. The hyperbolic functions have the accuracy
+/// of the element type's own implementation.
+///
+/// | Function                 | Target                                                            |
+/// |--------------------------|-------------------------------------------------------------------|
+/// | `gamma`, `factorial`     | relative error 1e-14 for \|x\| ≤ 20, 5e-13 up to overflow         |
+/// |                          | exact for integers as long as the result fits into the mantissa   |
+/// | `ln_gamma`               | absolute error 1e-14 · max(1, \|ln Γ(x)\|)                        |
+/// | `beta`                   | relative error 5e-14 for 0 < x, y ≤ 10, 1e-11 up to 1000          |
+/// | `erf`, `erfc`, `erf_inv` | relative error 1e-14, for `erfc` down to its underflow            |
+/// | `bessel_j0`, `bessel_j1` | absolute error 1e-15 for \|x\| ≤ 100, 1e-13 beyond                |
+/// | `bessel_y0`, `bessel_y1` | absolute error 1e-15 · max(1, \|Y(x)\|) for x ≤ 100, 1e-13 beyond |
+///
+/// Gamma functions of positive integers are products of integers, gamma functions of other
+/// arguments use the Lanczos approximation with the reflection formula, error functions a
+/// series and a continued fraction, the inverse error function Halley's method, and Bessel
+/// functions Miller's backward recurrence below x = 25 and Hankel's asymptotic expansion above.
+///
+/// # Example
+///
+/// Reference values computed with 30 significant digits, checked with the relative error
+/// of the gamma and error functions and the absolute error of the Bessel functions:
+///
+/// ```
+/// use smsflib::prelude::*;
+///
+/// type Function = fn(&mut DynamicSizedStack<f64>) -> Result<(), smsflib::StackError>;
+///
+/// let relative: [(Function, f64, f64, f64); 10] = [
+///     (|s| s.gamma(), 0.5, 1.772453850905516, 1e-14),
+///     (|s| s.gamma(), -2.5, -0.9453087204829419, 1e-14),
+///     (|s| s.gamma(), 30.25, 2.0628053137753467e31, 5e-13),
+///     (|s| s.ln_gamma(), -3.5, -1.309006684993042, 1e-14),
+///     (|s| s.factorial(), 10.0, 3628800.0, 0.0),
+///     (|s| s.erf(), 1.5, 0.9661051464753108, 1e-14),
+///     (|s| s.erfc(), 0.5, 0.4795001221869535, 1e-14),
+///     (|s| s.erfc(), 5.0, 1.537459794428035e-12, 1e-14),
+///     (|s| s.erf_inv(), 0.5, 0.4769362762044699, 1e-14),
+///     (|s| s.erf_inv(), -0.999, -2.3267537655135246, 1e-14),
+/// ];
+///
+/// let absolute: [(Function, f64, f64, f64); 10] = [
+///     (|s| s.bessel_j0(), 40.0, 0.00736689058423729, 1e-15),
+///     (|s| s.bessel_j1(), 40.0, 0.126038318037585, 1e-15),
+///     (|s| s.bessel_y0(), 40.0, 0.12593641705826092, 1e-15),
+///     (|s| s.bessel_y1(), 40.0, -0.005793505821549633, 1e-15),
+///     (|s| s.bessel_j0(), 2.5, -0.048383776468198, 1e-15),
+///     (|s| s.bessel_y1(), 0.5, -1.471472392670243, 1e-15),
+///     (|s| s.bessel_j0(), 1e-300, 1.0, 1e-15),
+///     (|s| s.bessel_j1(), 1e-300, 5e-301, 1e-15),
+///     (|s| s.bessel_y0(), 1e-310, -454.4938756003538866, 1e-15),
+///     (|s| s.bessel_y1(), 1e-300, -6.366197723675813e299, 1e-15),
+/// ];
+///
+/// let value = |function: &Function, x: f64| {
+///     let mut stack = DynamicSizedStack::<f64>::clone_from_slice(&[x]);
+///     assert_eq!(function(&mut stack), Ok(()));
+///     *stack.get(0).unwrap()
+/// };
+///
+/// for (function, x, expected, tolerance) in relative.iter() {
+///     let value = value(function, *x);
+///     assert!((value - expected).abs() <= tolerance * expected.abs(), "{} != {}", value, expected);
+/// }
+///
+/// for (function, x, expected, tolerance) in absolute.iter() {
+///     let value = value(function, *x);
+///     let scale = expected.abs().max(1.0);
+///     assert!((value - expected).abs() <= tolerance * scale, "{} != {}", value, expected);
+/// }
+/// ```
+pub trait SpecialFunctions: InPlaceFnApplication
+where
+    <Self as InPlaceFnApplication>::Elem: num_traits::Float + std::fmt::Debug,
+{
+    /// Hyperbolic sine of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.sinh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.5210953054937474);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn sinh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("sinh", x.sinh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Hyperbolic cosine of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.cosh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 1.1276259652063807);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn cosh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("cosh", x.cosh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Hyperbolic tangent of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.tanh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.46211715726000974);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn tanh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("tanh", x.tanh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Inverse hyperbolic sine of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.asinh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.48121182505960347);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn asinh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("asinh", x.asinh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Inverse hyperbolic cosine of X, defined for X ≥ 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.0, 1.0, 2.0, 3.0);
+    /// stack.acosh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 1.3169578969248168);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// let res = stack.acosh();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("acosh", &[0.5])));
+    /// ```
+    fn acosh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("acosh", x.acosh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Inverse hyperbolic tangent of X, defined for |X| ≤ 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.atanh();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.5493061443340549);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn atanh(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("atanh", x.atanh(), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Gamma function of X, undefined at zero and the negative integers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(4.5, 1.0, 2.0, 3.0);
+    /// stack.gamma();
+    ///
+    /// assert_approx_eq!(*stack.x(), 11.631728396567448);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.0, 1.0, 2.0, 3.0);
+    /// stack.gamma();
+    ///
+    /// assert_eq!(*stack.x(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-2.0, 1.0, 2.0, 3.0);
+    /// let res = stack.gamma();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("gamma", &[-2.0])));
+    /// assert_eq!(*stack.x(), -2.0);
+    /// ```
+    fn gamma(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("gamma", special_functions::gamma(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Natural logarithm of the absolute value of the gamma function of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(100.0, 1.0, 2.0, 3.0);
+    /// stack.ln_gamma();
+    ///
+    /// assert_approx_eq!(*stack.x(), 359.1342053695754);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.0, 1.0, 2.0, 3.0);
+    /// stack.ln_gamma();
+    ///
+    /// assert_eq!(*stack.x(), 0.0);
+    /// ```
+    fn ln_gamma(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("ln_gamma", special_functions::ln_gamma(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Factorial of X, generalised to non-integers as Γ(X + 1)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.factorial();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.886226925452758);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(5.0, 0.0, 2.0, 3.0);
+    /// stack.factorial();
+    ///
+    /// assert_eq!(*stack.x(), 120.0);
+    ///
+    /// stack.swap();
+    /// stack.factorial();
+    ///
+    /// assert_eq!(*stack.x(), 1.0);
+    /// ```
+    fn factorial(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result(
+                "factorial",
+                special_functions::gamma(*x + num_traits::one()),
+                &[*x],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Beta function of X and Y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.5, 2.5, 2.0, 3.0);
+    /// stack.beta();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.19634954084936207);
+    /// assert_eq!(*stack.y(), 2.0);
+    /// assert_eq!(*stack.z(), 3.0);
+    /// assert_eq!(*stack.t(), 3.0);
+    /// ```
+    fn beta(&mut self) -> Result<(), crate::StackError> {
+        self.try_binary_fn_in_place_first_arg(
+            |x: &mut <Self as InPlaceFnApplication>::Elem,
+             y: &<Self as InPlaceFnApplication>::Elem| {
+                *x = checked_result("beta", special_functions::beta(*y, *x), &[*x, *y])?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Error function of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.3, 1.0, 2.0, 3.0);
+    /// stack.erf();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.3286267594591274);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn erf(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("erf", special_functions::erf(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Complementary error function 1 - erf(X), accurate also where erf(X) is close to one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(5.0, 1.0, 2.0, 3.0);
+    /// stack.erfc();
+    ///
+    /// assert_approx_eq!(*stack.x() / 1.537459794428035e-12, 1.0);
+    /// assert_eq!(*stack.y(), 1.0);
+    /// ```
+    fn erfc(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("erfc", special_functions::erfc(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Inverse error function of X, defined for |X| ≤ 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-0.999, 1.0, 2.0, 3.0);
+    /// stack.erf_inv();
+    ///
+    /// assert_approx_eq!(*stack.x(), -2.3267537655135246);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.5, 1.0, 2.0, 3.0);
+    /// let res = stack.erf_inv();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("erf_inv", &[1.5])));
+    /// ```
+    fn erf_inv(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("erf_inv", special_functions::erf_inv(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Bessel function of the first kind of order zero of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.bessel_j0();
+    ///
+    /// assert_approx_eq!(*stack.x(), -0.048383776468198);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::NAN, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_j0();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert!(stack.x().is_nan());
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::NEG_INFINITY, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_j0();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0.0);
+    /// ```
+    fn bessel_j0(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("bessel_j0", special_functions::bessel_j0(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Bessel function of the first kind of order one of X
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(2.5, 1.0, 2.0, 3.0);
+    /// stack.bessel_j1();
+    ///
+    /// assert_approx_eq!(*stack.x(), 0.49709410246427405);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::NAN, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_j1();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert!(stack.x().is_nan());
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::INFINITY, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_j1();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0.0);
+    /// ```
+    fn bessel_j1(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("bessel_j1", special_functions::bessel_j1(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Bessel function of the second kind of order zero of X, defined for X ≥ 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.bessel_y0();
+    ///
+    /// assert_approx_eq!(*stack.x(), -0.44451873350670656);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::NAN, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_y0();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert!(stack.x().is_nan());
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::INFINITY, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_y0();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(-1.0, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_y0();
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::domain_error("bessel_y0", &[-1.0])));
+    /// ```
+    fn bessel_y0(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("bessel_y0", special_functions::bessel_y0(*x), &[*x])?;
+            Ok(())
+        })
+    }
+
+    /// Bessel function of the second kind of order one of X, defined for X ≥ 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    /// use assert_approx_eq::assert_approx_eq;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(0.5, 1.0, 2.0, 3.0);
+    /// stack.bessel_y1();
+    ///
+    /// assert_approx_eq!(*stack.x(), -1.471472392670243);
+    /// assert_eq!(*stack.y(), 1.0);
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::NAN, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_y1();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert!(stack.x().is_nan());
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(f64::INFINITY, 1.0, 2.0, 3.0);
+    /// let res = stack.bessel_y1();
+    ///
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(*stack.x(), 0.0);
+    /// ```
+    fn bessel_y1(&mut self) -> Result<(), crate::StackError> {
+        self.try_unary_fn_in_place(|x: &mut <Self as InPlaceFnApplication>::Elem| {
+            *x = checked_result("bessel_y1", special_functions::bessel_y1(*x), &[*x])?;
+            Ok(())
+        })
+    }
+}
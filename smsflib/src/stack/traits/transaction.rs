@@ -0,0 +1,62 @@
+/// Execution of operation sequences as a single unit.
+///
+/// The stack is snapshotted before the sequence runs and restored if it fails,
+/// including registers such as LastX. Transactions can be nested: a failed inner
+/// transaction only rolls back its own changes.
+pub trait Transaction: Clone {
+    /// Run `operations` on the stack, rolling back every change if they return [Err].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = DynamicSizedStack::<i32>::clone_from_slice(&[5, 3, 2]);
+    /// let res = stack.transaction(|s| {
+    ///     s.add()?;
+    ///     s.add()?;
+    ///     s.add()
+    /// });
+    ///
+    /// assert_eq!(res, Err(smsflib::StackError::NotEnoughOperands{num_required: 2, num_available: 1}));
+    ///
+    /// assert_eq!(stack.len(), 3);
+    /// assert_eq!(stack.get(0), Some(&2));
+    /// assert_eq!(stack.get(1), Some(&3));
+    /// assert_eq!(stack.get(2), Some(&5));
+    /// assert_eq!(stack.last_x_value(), None);
+    /// ```
+    ///
+    /// ```
+    /// use smsflib::prelude::*;
+    ///
+    /// let mut stack = ClassicStack::<f64>::new(1.0, 2.0, 3.0, 4.0);
+    /// let res = stack.transaction(|outer| {
+    ///     outer.add()?;
+    ///     let inner_res = outer.transaction(|inner| {
+    ///         inner.push(-1.0)?;
+    ///         inner.ln()
+    ///     });
+    ///     assert_eq!(inner_res, Err(smsflib::StackError::domain_error("ln", &[-1.0])));
+    ///     outer.multiply()
+    /// });
+    ///
+    /// assert_eq!(res, Ok(()));
+    ///
+    /// assert_eq!(*stack.x(), 9.0);
+    /// assert_eq!(*stack.y(), 4.0);
+    /// assert_eq!(*stack.z(), 4.0);
+    /// assert_eq!(*stack.t(), 4.0);
+    /// ```
+    fn transaction<R, F: FnOnce(&mut Self) -> Result<R, crate::StackError>>(
+        &mut self,
+        operations: F,
+    ) -> Result<R, crate::StackError> {
+        let snapshot = self.clone();
+        let res = operations(self);
+        if res.is_err() {
+            *self = snapshot;
+        }
+        res
+    }
+}
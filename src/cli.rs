@@ -0,0 +1,386 @@
+use crate::output::{render, OutputFormat, OutputScope};
+use crate::repl::{default_registry, execute_line, execute_token, Backend};
+use smsflib::stack::Transaction;
+use smsflib::StackError;
+use std::io::{BufRead, Write};
+
+pub const USAGE: &str = "\
+Usage: smsf [OPTIONS]
+       smsf [OPTIONS] [--] TOKEN...
+       smsf [OPTIONS] -
+
+Without arguments, start an interactive session.
+With tokens, evaluate them in RPN and print the result.
+With '-', read RPN lines from standard input and print the result after each line.
+
+Options:
+  --format <plain|json>  Output format of results (default: plain)
+  --json                 Same as '--format json'
+  --stack                Print the whole stack instead of X
+  --backend <dynamic|classic>
+                         Unbounded stack or four registers X, Y, Z, T (default: dynamic)
+  --help                 Print this message
+
+Exit codes:
+  0  success
+  1  I/O error
+  2  invalid command line
+  3  unknown token
+  4  not enough operands
+  5  other stack error
+  6  invalid operands (domain error, division by zero, overflow, type mismatch)";
+
+/// Process exit codes
+pub mod exit_code {
+    pub const SUCCESS: i32 = 0;
+    pub const IO_ERROR: i32 = 1;
+    pub const USAGE: i32 = 2;
+    pub const UNKNOWN_TOKEN: i32 = 3;
+    pub const NOT_ENOUGH_OPERANDS: i32 = 4;
+    pub const OTHER_STACK_ERROR: i32 = 5;
+    pub const INVALID_OPERANDS: i32 = 6;
+}
+
+/// Map an evaluation error to the exit code of the process
+pub fn exit_code_for(error: &StackError) -> i32 {
+    match error {
+        StackError::UnknownOperation { .. } => exit_code::UNKNOWN_TOKEN,
+        StackError::NotEnoughOperands { .. } => exit_code::NOT_ENOUGH_OPERANDS,
+        StackError::DomainError { .. }
+        | StackError::DivisionByZero { .. }
+        | StackError::Overflow { .. }
+        | StackError::TypeMismatch { .. } => exit_code::INVALID_OPERANDS,
+        StackError::StackOverflow { .. } | StackError::EmptyLastX | StackError::Other => {
+            exit_code::OTHER_STACK_ERROR
+        }
+    }
+}
+
+/// What the binary has been asked to do
+#[derive(Debug, PartialEq)]
+pub enum Mode {
+    Interactive,
+    OneShot(Vec<String>),
+    Filter,
+    Help,
+}
+
+/// Parsed command line
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub format: OutputFormat,
+    pub scope: OutputScope,
+    pub backend: Backend,
+}
+
+impl Options {
+    /// Parse the command line arguments, excluding the program name.
+    ///
+    /// Options are only recognised before the first token, so negative numbers such as `-4`
+    /// can be passed as tokens without `--`.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut format = OutputFormat::Plain;
+        let mut scope = OutputScope::X;
+        let mut backend = Backend::Dynamic;
+        let mut help = false;
+
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.peek() {
+            match arg.as_str() {
+                "--" => {
+                    args.next();
+                    break;
+                }
+                "--help" | "-h" => help = true,
+                "--json" => format = OutputFormat::Json,
+                "--stack" => scope = OutputScope::WholeStack,
+                "--format" => {
+                    args.next();
+                    format = args
+                        .peek()
+                        .ok_or_else(|| "missing value for '--format'".to_string())?
+                        .parse()?;
+                }
+                "--backend" => {
+                    args.next();
+                    backend = args
+                        .peek()
+                        .ok_or_else(|| "missing value for '--backend'".to_string())?
+                        .parse()?;
+                }
+                _ => {
+                    if let Some(value) = arg.strip_prefix("--format=") {
+                        format = value.parse()?;
+                    } else if let Some(value) = arg.strip_prefix("--backend=") {
+                        backend = value.parse()?;
+                    } else if arg.starts_with("--") {
+                        return Err(format!("unknown option '{}'", arg));
+                    } else {
+                        break;
+                    }
+                }
+            }
+            args.next();
+        }
+
+        let tokens: Vec<String> = args.collect();
+        let mode = if help {
+            Mode::Help
+        } else if tokens.is_empty() {
+            Mode::Interactive
+        } else if tokens == ["-"] {
+            Mode::Filter
+        } else {
+            Mode::OneShot(tokens)
+        };
+
+        Ok(Options {
+            mode,
+            format,
+            scope,
+            backend,
+        })
+    }
+}
+
+/// Evaluate the tokens on a fresh stack and print the result once.
+///
+/// Return the exit code of the process.
+pub fn run_one_shot<W: Write, E: Write>(
+    tokens: &[String],
+    format: OutputFormat,
+    scope: OutputScope,
+    backend: Backend,
+    mut output: W,
+    mut error_output: E,
+) -> std::io::Result<i32> {
+    let registry = default_registry();
+    let mut stack = backend.new_stack();
+
+    for token in tokens {
+        if let Err(e) = execute_token(&registry, &mut stack, token) {
+            writeln!(error_output, "smsf: {}", e)?;
+            return Ok(exit_code_for(&e));
+        }
+    }
+    writeln!(output, "{}", render(&stack, format, scope))?;
+    Ok(exit_code::SUCCESS)
+}
+
+/// Evaluate every input line on a shared stack and print the result after each line.
+///
+/// A failing line is reported on the error output, leaves the stack as it was before the line
+/// and produces no result, processing continues with the next line.
+/// Return the exit code of the first failure, or success.
+pub fn run_filter<R: BufRead, W: Write, E: Write>(
+    input: R,
+    format: OutputFormat,
+    scope: OutputScope,
+    backend: Backend,
+    mut output: W,
+    mut error_output: E,
+) -> std::io::Result<i32> {
+    let registry = default_registry();
+    let mut stack = backend.new_stack();
+    let mut exit_code = exit_code::SUCCESS;
+
+    for (line_idx, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match stack.transaction(|stack| execute_line(&registry, stack, &line)) {
+            Ok(()) => writeln!(output, "{}", render(&stack, format, scope))?,
+            Err(e) => {
+                writeln!(error_output, "smsf: line {}: {}", line_idx + 1, e)?;
+                if exit_code == exit_code::SUCCESS {
+                    exit_code = exit_code_for(&e);
+                }
+            }
+        }
+        output.flush()?;
+    }
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn one_shot(args: &[&str]) -> (i32, String, String) {
+        let options = parse(args).unwrap();
+        let tokens = match options.mode {
+            Mode::OneShot(tokens) => tokens,
+            mode => panic!("expected one-shot mode, got {:?}", mode),
+        };
+        let mut output = Vec::new();
+        let mut error_output = Vec::new();
+        let code = run_one_shot(
+            &tokens,
+            options.format,
+            options.scope,
+            options.backend,
+            &mut output,
+            &mut error_output,
+        )
+        .unwrap();
+        (
+            code,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(error_output).unwrap(),
+        )
+    }
+
+    #[test]
+    fn parse_defaults_to_interactive() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Options {
+                mode: Mode::Interactive,
+                format: OutputFormat::Plain,
+                scope: OutputScope::X,
+                backend: Backend::Dynamic,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_modes() {
+        assert_eq!(parse(&["--help"]).unwrap().mode, Mode::Help);
+        assert_eq!(parse(&["-h", "1"]).unwrap().mode, Mode::Help);
+        assert_eq!(parse(&["-"]).unwrap().mode, Mode::Filter);
+        assert_eq!(
+            parse(&["3", "-4", "+"]).unwrap().mode,
+            Mode::OneShot(vec!["3".to_string(), "-4".to_string(), "+".to_string()])
+        );
+        assert_eq!(
+            parse(&["--", "--json"]).unwrap().mode,
+            Mode::OneShot(vec!["--json".to_string()])
+        );
+    }
+
+    #[test]
+    fn parse_flags() {
+        let options = parse(&["--json", "--stack", "--backend", "classic", "1"]).unwrap();
+        assert_eq!(options.format, OutputFormat::Json);
+        assert_eq!(options.scope, OutputScope::WholeStack);
+        assert_eq!(options.backend, Backend::Classic);
+
+        let options = parse(&["--format=json", "--backend=classic", "-"]).unwrap();
+        assert_eq!(options.format, OutputFormat::Json);
+        assert_eq!(options.backend, Backend::Classic);
+
+        let options = parse(&["--json", "--format", "plain", "1"]).unwrap();
+        assert_eq!(options.format, OutputFormat::Plain);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_options() {
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--backend=stack"]).is_err());
+    }
+
+    #[test]
+    fn exit_code_per_error_variant() {
+        let cases = [
+            (
+                StackError::NotEnoughOperands {
+                    num_required: 2,
+                    num_available: 1,
+                },
+                exit_code::NOT_ENOUGH_OPERANDS,
+            ),
+            (
+                StackError::domain_error("ln", &[-1.0]),
+                exit_code::INVALID_OPERANDS,
+            ),
+            (
+                StackError::division_by_zero("divide", &[0.0, 1.0]),
+                exit_code::INVALID_OPERANDS,
+            ),
+            (
+                StackError::overflow("add", &[1, i32::MAX]),
+                exit_code::INVALID_OPERANDS,
+            ),
+            (
+                StackError::UnknownOperation {
+                    name: "foo".to_string(),
+                },
+                exit_code::UNKNOWN_TOKEN,
+            ),
+            (
+                StackError::type_mismatch("factorial", "an integer", &[2.5]),
+                exit_code::INVALID_OPERANDS,
+            ),
+            (
+                StackError::StackOverflow { max_depth: 4 },
+                exit_code::OTHER_STACK_ERROR,
+            ),
+            (StackError::EmptyLastX, exit_code::OTHER_STACK_ERROR),
+            (StackError::Other, exit_code::OTHER_STACK_ERROR),
+        ];
+        for (error, code) in cases.iter() {
+            assert_eq!(exit_code_for(error), *code, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn one_shot_prints_result() {
+        assert_eq!(
+            one_shot(&["3", "4", "+"]),
+            (exit_code::SUCCESS, "7\n".to_string(), String::new())
+        );
+        assert_eq!(
+            one_shot(&["--json", "--stack", "1", "2"]),
+            (
+                exit_code::SUCCESS,
+                "{\"stack\":[2,1]}\n".to_string(),
+                String::new()
+            )
+        );
+    }
+
+    #[test]
+    fn one_shot_maps_errors_to_exit_codes() {
+        assert_eq!(
+            one_shot(&["1", "foo"]),
+            (
+                exit_code::UNKNOWN_TOKEN,
+                String::new(),
+                "smsf: unknown operation 'foo'\n".to_string()
+            )
+        );
+        assert_eq!(one_shot(&["1", "+"]).0, exit_code::NOT_ENOUGH_OPERANDS);
+        assert_eq!(one_shot(&["-1", "ln"]).0, exit_code::INVALID_OPERANDS);
+    }
+
+    #[test]
+    fn filter_reports_line_numbers() {
+        let mut output = Vec::new();
+        let mut error_output = Vec::new();
+        let code = run_filter(
+            "1 2\n\n+ foo\n4 +\n".as_bytes(),
+            OutputFormat::Plain,
+            OutputScope::WholeStack,
+            Backend::Dynamic,
+            &mut output,
+            &mut error_output,
+        )
+        .unwrap();
+
+        assert_eq!(code, exit_code::UNKNOWN_TOKEN);
+        // The failed line does not leave its sum on the stack
+        assert_eq!(String::from_utf8(output).unwrap(), "2 1\n6 1\n");
+        assert_eq!(
+            String::from_utf8(error_output).unwrap(),
+            "smsf: line 3: unknown operation 'foo'\n"
+        );
+    }
+}
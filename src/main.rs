@@ -1,48 +1,52 @@
-use smsflib::prelude::*;
+mod cli;
+mod output;
+mod repl;
+
+use cli::{exit_code, Mode, Options};
 
 fn main() {
-    let mut stack = ClassicStack::<u32>::new(1, 2, 3, 4);
-    println!("{}", stack);
-    let _ = stack.rotate_up().unwrap();
-    println!("After rotation (up):\n{}", stack);
-    let _ = stack.rotate_down().unwrap();
-    println!("After rotation (down):\n{}", stack);
-    let _ = stack.swap().unwrap();
-    println!("After swap:\n{}", stack);
-    let _ = stack.swap().unwrap();
-    println!("After swap:\n{}", stack);
-    let _ = stack.drop().unwrap();
-    println!("After drop:\n{}", stack);
-    dbg! {stack.pop().unwrap()};
-    println!("After pop:\n{}", stack);
-    let _ = stack.push(7).unwrap();
-    println!("After pushing 7:\n{}", stack);
-    let _ = stack.clear().unwrap();
-    println!("After clearing:\n{}", stack);
-    let _ = stack.push(1).unwrap();
-    println!("After pushing 1:\n{}", stack);
-    let _ = stack.push(2).unwrap();
-    println!("After pushing 2:\n{}", stack);
-    let _ = stack.push(3).unwrap();
-    println!("After pushing 3:\n{}", stack);
-    let _ = stack.push(4).unwrap();
-    println!("After pushing 4:\n{}", stack);
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("smsf: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(exit_code::USAGE);
+        }
+    };
+
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    let stderr = std::io::stderr();
+    let res = match options.mode {
+        Mode::Help => {
+            println!("{}", cli::USAGE);
+            Ok(exit_code::SUCCESS)
+        }
+        Mode::Interactive => {
+            repl::run(stdin.lock(), stdout.lock(), options.backend).map(|_| exit_code::SUCCESS)
+        }
+        Mode::OneShot(tokens) => cli::run_one_shot(
+            &tokens,
+            options.format,
+            options.scope,
+            options.backend,
+            stdout.lock(),
+            stderr.lock(),
+        ),
+        Mode::Filter => cli::run_filter(
+            stdin.lock(),
+            options.format,
+            options.scope,
+            options.backend,
+            stdout.lock(),
+            stderr.lock(),
+        ),
+    };
 
-    let mut stack = DynamicSizedStack::<f64>::new();
-    let _ = stack.push(10.0).unwrap();
-    let _ = stack.push(7.0).unwrap();
-    let _ = stack.push(1.2).unwrap();
-    println!("{}", stack);
-    let _ = dbg! {stack.ln()};
-    println!("{}", stack);
-    let _ = dbg! {stack.push(123.0)};
-    println!("{}", stack);
-    let _ = dbg! {stack.divide()};
-    println!("{}", stack);
-    let _ = dbg! {stack.multiply()};
-    println!("{}", stack);
-    let _ = dbg! {stack.divide()};
-    println!("{}", stack);
-    let _ = dbg! { stack.divide() };
-    println!("{}", stack);
+    match res {
+        Ok(code) => std::process::exit(code),
+        Err(e) => {
+            eprintln!("smsf: I/O error: {}", e);
+            std::process::exit(exit_code::IO_ERROR);
+        }
+    }
 }
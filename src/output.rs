@@ -0,0 +1,152 @@
+use crate::repl::BoxedStack;
+
+/// How results are printed in one-shot and filter mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    /// Human-readable numbers
+    Plain,
+    /// One JSON object per result
+    Json,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("unknown output format '{}'", s)),
+        }
+    }
+}
+
+/// Which part of the stack is printed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputScope {
+    /// Only the lowermost level
+    X,
+    /// All levels, starting with the lowermost one
+    WholeStack,
+}
+
+/// Render the stack as a single line
+///
+/// Plain output separates levels by spaces, starting with X.
+/// JSON output is either `{"x":…}` or `{"stack":[…]}`, with `null` for an empty X
+/// and for values that JSON cannot represent, such as NaN and infinities.
+pub fn render(stack: &BoxedStack, format: OutputFormat, scope: OutputScope) -> String {
+    let levels: Vec<f64> = match scope {
+        OutputScope::X => stack.get(0).into_iter().copied().collect(),
+        OutputScope::WholeStack => (0..stack.len())
+            .filter_map(|idx| stack.get(idx))
+            .copied()
+            .collect(),
+    };
+
+    match (format, scope) {
+        (OutputFormat::Plain, _) => levels
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+        (OutputFormat::Json, OutputScope::X) => format!(
+            "{{\"x\":{}}}",
+            levels
+                .first()
+                .map_or_else(|| "null".to_string(), |&value| json_number(value))
+        ),
+        (OutputFormat::Json, OutputScope::WholeStack) => format!(
+            "{{\"stack\":[{}]}}",
+            levels
+                .iter()
+                .map(|&value| json_number(value))
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+    }
+}
+
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repl::Backend;
+
+    fn stack_of(values: &[f64]) -> BoxedStack {
+        let mut stack = Backend::Dynamic.new_stack();
+        for &value in values {
+            stack.push(value).unwrap();
+        }
+        stack
+    }
+
+    #[test]
+    fn render_plain() {
+        let stack = stack_of(&[1.5, -2.0, 3.0]);
+
+        assert_eq!(render(&stack, OutputFormat::Plain, OutputScope::X), "3");
+        assert_eq!(
+            render(&stack, OutputFormat::Plain, OutputScope::WholeStack),
+            "3 -2 1.5"
+        );
+    }
+
+    #[test]
+    fn render_json() {
+        let stack = stack_of(&[1.5, -2.0, 3.0]);
+
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::X),
+            "{\"x\":3}"
+        );
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::WholeStack),
+            "{\"stack\":[3,-2,1.5]}"
+        );
+    }
+
+    #[test]
+    fn render_json_non_finite_as_null() {
+        let stack = stack_of(&[f64::INFINITY, f64::NAN]);
+
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::X),
+            "{\"x\":null}"
+        );
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::WholeStack),
+            "{\"stack\":[null,null]}"
+        );
+        assert_eq!(render(&stack, OutputFormat::Plain, OutputScope::X), "NaN");
+    }
+
+    #[test]
+    fn render_empty_stack() {
+        let stack = stack_of(&[]);
+
+        assert_eq!(render(&stack, OutputFormat::Plain, OutputScope::X), "");
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::X),
+            "{\"x\":null}"
+        );
+        assert_eq!(
+            render(&stack, OutputFormat::Json, OutputScope::WholeStack),
+            "{\"stack\":[]}"
+        );
+    }
+
+    #[test]
+    fn parse_output_format() {
+        assert_eq!("plain".parse(), Ok(OutputFormat::Plain));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use smsflib::prelude::*;
+use smsflib::registry::{Arity, Operation, OperationRegistry};
+use smsflib::StackError;
+use std::io::{BufRead, Write};
+
+/// Stack type the calculator operates on, the backend is chosen at runtime
+pub type BoxedStack = Box<dyn Stack<f64>>;
+
+/// Stack implementation backing the calculator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    /// HP-like stack with the four registers X, Y, Z and T
+    Classic,
+    /// RPL-like stack growing as needed
+    Dynamic,
+}
+
+impl Backend {
+    /// Create an empty stack of this backend
+    pub fn new_stack(self) -> BoxedStack {
+        match self {
+            Backend::Classic => Box::new(ClassicStack::<f64>::new_zero()),
+            Backend::Dynamic => Box::new(DynamicSizedStack::<f64>::new()),
+        }
+    }
+}
+
+impl std::str::FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Backend::Classic),
+            "dynamic" => Ok(Backend::Dynamic),
+            _ => Err(format!("unknown backend '{}'", s)),
+        }
+    }
+}
+
+/// Create a registry with all operations available in the calculator
+pub fn default_registry() -> OperationRegistry<BoxedStack> {
+    let mut registry = OperationRegistry::new();
+    registry
+        .register_basic_stack_operations()
+        .register_extended_stack_operations()
+        .register_last_x_operations()
+        .register_basic_math_operations()
+        .register_signed_math_operations()
+        .register_percentage_operations()
+        .register_float_math_operations()
+        .register_fraction_operations()
+        .register_special_functions();
+    registry
+        .register(angle_mode_operation(
+            "deg",
+            "Take and return angles in degrees",
+            |stack| set_angle_mode(stack, AngleMode::Degrees),
+        ))
+        .register(angle_mode_operation(
+            "rad",
+            "Take and return angles in radians",
+            |stack| set_angle_mode(stack, AngleMode::Radians),
+        ))
+        .register(angle_mode_operation(
+            "grad",
+            "Take and return angles in gradians",
+            |stack| set_angle_mode(stack, AngleMode::Gradians),
+        ))
+        .register(angle_mode_operation(
+            "turns",
+            "Take and return angles in full turns",
+            |stack| set_angle_mode(stack, AngleMode::Turns),
+        ));
+    registry
+}
+
+/// Create an operation switching the angle mode, which leaves the levels untouched
+fn angle_mode_operation(
+    name: &'static str,
+    description: &'static str,
+    function: fn(&mut BoxedStack) -> Result<(), StackError>,
+) -> Operation<BoxedStack> {
+    Operation::new(name, &[], Arity::new(0, 0), description, function)
+}
+
+/// Switch the unit of angles in trigonometric operations
+fn set_angle_mode(stack: &mut BoxedStack, mode: AngleMode) -> Result<(), StackError> {
+    stack.set_angle_mode(mode);
+    Ok(())
+}
+
+/// Run an interactive read-eval-print loop until the input is exhausted or `quit` is entered.
+///
+/// Every line is split into whitespace-separated tokens which are executed from left to right.
+/// The tokens before `quit` (or `q`) are still executed, the ones after it are ignored.
+/// If a token fails, the error is reported and the rest of the line is skipped,
+/// the session itself continues. The active angle mode and the stack are printed after each line.
+/// `help` lists all available operations.
+pub fn run<R: BufRead, W: Write>(input: R, mut output: W, backend: Backend) -> std::io::Result<()> {
+    let registry = default_registry();
+    let mut stack = backend.new_stack();
+
+    for line in input.lines() {
+        let line = line?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let quit_position = tokens
+            .iter()
+            .position(|token| *token == "quit" || *token == "q");
+        let tokens = &tokens[..quit_position.unwrap_or(tokens.len())];
+        if tokens == ["help"] {
+            print_help(&registry, &mut output)?;
+        } else if !tokens.is_empty() || quit_position.is_none() {
+            if let Err(e) = execute_line(&registry, &mut stack, &tokens.join(" ")) {
+                writeln!(output, "Error: {}", e)?;
+            }
+            writeln!(output, "[{}]", stack.angle_mode())?;
+            write!(output, "{}", stack)?;
+            output.flush()?;
+        }
+        if quit_position.is_some() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Print all operations with their aliases, arity and description
+fn print_help<W: Write>(
+    registry: &OperationRegistry<BoxedStack>,
+    output: &mut W,
+) -> std::io::Result<()> {
+    for operation in registry.iter() {
+        let arity = operation.arity();
+        writeln!(
+            output,
+            "{:<16} {:<16} {} -> {}  {}",
+            operation.name(),
+            operation.aliases().join(" "),
+            arity.num_inputs,
+            arity.num_outputs,
+            operation.description()
+        )?;
+    }
+    Ok(())
+}
+
+/// Execute all tokens of a line, stopping at the first failing one
+pub fn execute_line(
+    registry: &OperationRegistry<BoxedStack>,
+    stack: &mut BoxedStack,
+    line: &str,
+) -> Result<(), StackError> {
+    for token in line.split_whitespace() {
+        execute_token(registry, stack, token)?;
+    }
+    Ok(())
+}
+
+/// Push a number or apply an operation given by its name or alias
+pub fn execute_token(
+    registry: &OperationRegistry<BoxedStack>,
+    stack: &mut BoxedStack,
+    token: &str,
+) -> Result<(), StackError> {
+    match token.parse::<f64>() {
+        Ok(value) => stack.push(value),
+        Err(_) => registry.apply(token, stack),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_script(script: &str) -> String {
+        let mut output = Vec::new();
+        run(script.as_bytes(), &mut output, Backend::Dynamic).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn prints_stack_after_each_line() {
+        let output = run_script("3\n4 +\n");
+        assert_eq!(output.matches("0: ").count(), 2, "{}", output);
+        assert!(output.ends_with("0: 7\n"), "{}", output);
+    }
+
+    #[test]
+    fn executes_tokens_before_quit() {
+        assert_eq!(run_script("3 4 + q 5\n6\n"), run_script("3 4 +\n"));
+    }
+
+    #[test]
+    fn quit_alone_prints_nothing() {
+        assert_eq!(run_script("1\nquit\n2\n"), run_script("1\n"));
+    }
+
+    #[test]
+    fn reports_errors_and_continues() {
+        let output = run_script("1 foo 2\n3\n");
+        assert!(output.starts_with("Error: "), "{}", output);
+        assert!(output.contains("'foo'"), "{}", output);
+        assert!(output.ends_with("1: 1\n0: 3\n"), "{}", output);
+    }
+}